@@ -0,0 +1,13 @@
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Coordinate {
+    pub id: u32,
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Coordinate {
+    pub fn new(id: u32, x: f64, y: f64) -> Coordinate {
+        Coordinate { id, x, y }
+    }
+}
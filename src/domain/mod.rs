@@ -1,3 +1,4 @@
+pub mod coordinate;
 pub mod route;
 pub mod stop;
 mod tests;
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use crate::errors::vehicle::vehicle_overload::VehicleOverloadError;
 
 use super::{stop::Stop, vehicle::Vehicle};
@@ -25,6 +27,13 @@ impl Route {
         self.total_distance = Default::default()
     }
 
+    pub fn customers(&self) -> Vec<Stop> {
+        match self.stops.len() {
+            0..=2 => Vec::new(),
+            length => self.stops[1..length - 1].to_vec(),
+        }
+    }
+
     pub fn get_current_stop(&self) -> Option<&Stop> {
         self.stops.last()
     }
@@ -42,9 +51,32 @@ impl Route {
         Ok(())
     }
 
-    pub fn remove_stop(&mut self, stop_index: usize, distance_reduction: f64) {
-        self.stops.remove(stop_index);
+    pub fn force_add_stop(&mut self, stop: Stop, distance: f64) {
+        self.vehicle.force_load(stop.usage);
+
+        self.stops.push(stop);
+        self.total_distance += distance;
+    }
+
+    pub fn load(&self) -> u32 {
+        self.stops.iter().map(|stop| stop.usage).sum()
+    }
+
+    pub fn excess_load(&self) -> u32 {
+        (self.load() + 1).saturating_sub(self.vehicle.capacity)
+    }
+
+    pub fn can_replace_stop(&self, stop_index: usize, stop: &Stop) -> bool {
+        self.vehicle
+            .can_replace_load(self.stops[stop_index].usage, stop.usage)
+    }
+
+    pub fn remove_stop(&mut self, stop_index: usize, distance_reduction: f64) -> Stop {
+        let stop = self.stops.remove(stop_index);
+        self.vehicle.unload(stop.usage).ok();
         self.total_distance -= distance_reduction;
+
+        stop
     }
 
     pub fn add_stop_at(&mut self, stop: Stop, index: usize, distance_change: f64) {
@@ -52,6 +84,36 @@ impl Route {
         self.total_distance += distance_change;
     }
 
+    pub fn insert_stop(
+        &mut self,
+        stop: Stop,
+        index: usize,
+        distance_change: f64,
+    ) -> Result<(), VehicleOverloadError> {
+        self.vehicle.load(stop.usage)?;
+        self.add_stop_at(stop, index, distance_change);
+
+        Ok(())
+    }
+
+    pub fn replace_stop(
+        &mut self,
+        stop_index: usize,
+        stop: Stop,
+        distance_change: f64,
+    ) -> Result<Stop, VehicleOverloadError> {
+        if !self.can_replace_stop(stop_index, &stop) {
+            return Err(VehicleOverloadError::new());
+        }
+
+        let replaced = std::mem::replace(&mut self.stops[stop_index], stop);
+        self.vehicle.unload(replaced.usage).ok();
+        self.vehicle.load(stop.usage)?;
+        self.total_distance += distance_change;
+
+        Ok(replaced)
+    }
+
     pub fn add_multiple_stops_at(&mut self, stops: Vec<Stop>, index: usize, distance_change: f64) {
         self.stops.splice(index..index, stops);
 
@@ -62,6 +124,30 @@ impl Route {
         self.total_distance += distance_change;
     }
 
+    pub fn replace_stops(
+        &mut self,
+        range: Range<usize>,
+        stops: Vec<Stop>,
+        distance_change: f64,
+    ) -> Result<Vec<Stop>, VehicleOverloadError> {
+        let unloaded: u32 = self.stops[range.clone()]
+            .iter()
+            .map(|stop| stop.usage)
+            .sum();
+        let loaded: u32 = stops.iter().map(|stop| stop.usage).sum();
+
+        if !self.vehicle.can_replace_load(unloaded, loaded) {
+            return Err(VehicleOverloadError::new());
+        }
+
+        let replaced: Vec<Stop> = self.stops.splice(range, stops).collect();
+        self.vehicle.unload(unloaded).ok();
+        self.vehicle.load(loaded)?;
+        self.total_distance += distance_change;
+
+        Ok(replaced)
+    }
+
     pub fn total_distance(&self) -> f64 {
         self.total_distance
     }
@@ -33,3 +33,34 @@ fn route_cannot_overload_vehicle(full_stops: Vec<Stop>, distance_service: Distan
         panic!();
     }
 }
+
+#[rstest]
+fn route_can_be_forced_over_capacity(full_stops: Vec<Stop>, distance_service: DistanceService) {
+    let vehicle = Vehicle::new(0, 10);
+
+    let mut route = Route::new(vehicle);
+
+    route.add_stop(full_stops[0], 0.0).unwrap();
+
+    let distance = distance_service
+        .get_distance(&full_stops[0], &full_stops[1])
+        .unwrap();
+
+    route.force_add_stop(full_stops[1], distance);
+
+    assert_eq!(route.stops.len(), 2);
+    assert_eq!(route.load(), 105);
+    assert_eq!(route.excess_load(), 96);
+    assert_eq!(route.total_distance(), distance);
+}
+
+#[rstest]
+fn route_customers_exclude_depot(stops: Vec<Stop>, route_factory: RouteFactory) {
+    let route = route_factory(vec![stops[0], stops[2], stops[3], stops[1], stops[0]]);
+    let empty_route = route_factory(vec![stops[0], stops[0]]);
+
+    let ids: Vec<u32> = route.customers().iter().map(|stop| stop.id).collect();
+
+    assert_eq!(ids, vec![2, 3, 1]);
+    assert!(empty_route.customers().is_empty());
+}
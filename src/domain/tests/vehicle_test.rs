@@ -19,3 +19,13 @@ fn cannot_have_negative_usage() {
         panic!();
     }
 }
+
+#[test]
+fn can_force_vehicle_overload() {
+    let mut vehicle = Vehicle::new(0, 10);
+
+    vehicle.force_load(40);
+
+    assert_eq!(vehicle.usage(), 40);
+    assert!(!vehicle.can_support_load(0));
+}
@@ -23,10 +23,18 @@ impl Vehicle {
         self.usage = Default::default();
     }
 
+    pub fn usage(&self) -> u32 {
+        self.usage
+    }
+
     pub fn can_support_load(&self, quantity: u32) -> bool {
         self.usage + quantity < self.capacity
     }
 
+    pub fn can_replace_load(&self, unloaded: u32, loaded: u32) -> bool {
+        self.usage.saturating_sub(unloaded) + loaded < self.capacity
+    }
+
     pub fn load(&mut self, quantity: u32) -> Result<(), VehicleOverloadError> {
         if !self.can_support_load(quantity) {
             return Err(VehicleOverloadError::new());
@@ -36,12 +44,16 @@ impl Vehicle {
         Ok(())
     }
 
+    pub fn force_load(&mut self, quantity: u32) {
+        self.usage += quantity;
+    }
+
     pub fn unload(&mut self, quantity: u32) -> Result<(), NegativeVehicleCapacityError> {
         if quantity > self.usage {
             return Err(NegativeVehicleCapacityError::new());
         }
 
-        self.usage = quantity;
+        self.usage -= quantity;
         Ok(())
     }
 }
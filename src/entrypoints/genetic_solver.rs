@@ -1,8 +1,17 @@
+use std::{collections::HashSet, sync::Arc};
+
 use rand::thread_rng;
 
 use crate::{
-    domain::{stop::Stop, vehicle::Vehicle},
-    services::route::route_service::RouteService,
+    domain::{coordinate::Coordinate, stop::Stop, vehicle::Vehicle},
+    services::{
+        distance::{
+            coordinate_distance_oracle::CoordinateDistanceOracle,
+            distance_matrix_builder::DistanceMetric, distance_oracle::SharedDistanceOracle,
+            sparse_distance_matrix::SparseDistanceMatrix,
+        },
+        route::route_service::RouteService,
+    },
     solvers::genetic::genetic_solver::GeneticSolver,
 };
 
@@ -11,11 +20,15 @@ use super::{
     c_interfaces::c_distance_matrix::CDistanceMatrixEntry,
     factories::{copy_result, distance_matrix_factory, vector_factory},
     parameters::GeneticAlgorithmParameters,
+    solver_status::SolverStatus,
 };
 
+const DEPOT_ID: u32 = 0;
+
 /// # Safety
 ///
 /// Make sure that all the size are consistent.
+/// Stops without a depot are rejected without writing to `result`.
 #[no_mangle]
 pub unsafe extern "C" fn genetic_solver(
     vehicles_ptr: *mut Vehicle,
@@ -24,19 +37,77 @@ pub unsafe extern "C" fn genetic_solver(
     arg_sizes: ArgSizes,
     parameters: GeneticAlgorithmParameters,
     result: *mut u32,
-) {
-    let mut rng = thread_rng();
-
+) -> SolverStatus {
     let vehicles = vector_factory(vehicles_ptr, arg_sizes.vehicles);
     let stops = vector_factory(stops_ptr, arg_sizes.stops);
 
     let distances = distance_matrix_factory(distances_ptr, arg_sizes.distances);
+    let oracle: SharedDistanceOracle = Arc::new(SparseDistanceMatrix::new(&distances));
 
-    let route_service = RouteService::new(vehicles, &distances, stops.clone());
+    solve(vehicles, stops, oracle, parameters, result)
+}
+
+/// # Safety
+///
+/// Make sure that all the size are consistent: `coordinates_ptr` holds `arg_sizes.stops` entries.
+/// `metric` is 0 for Euclidean, 1 for rounded Euclidean, 2 for Manhattan and 3 for haversine.
+/// Unknown metrics and coordinates that do not match the stops one to one are rejected
+/// without writing to `result`.
+#[no_mangle]
+pub unsafe extern "C" fn genetic_solver_from_coordinates(
+    vehicles_ptr: *mut Vehicle,
+    stops_ptr: *mut Stop,
+    coordinates_ptr: *mut Coordinate,
+    metric: u32,
+    arg_sizes: ArgSizes,
+    parameters: GeneticAlgorithmParameters,
+    result: *mut u32,
+) -> SolverStatus {
+    let vehicles = vector_factory(vehicles_ptr, arg_sizes.vehicles);
+    let stops = vector_factory(stops_ptr, arg_sizes.stops);
+    let coordinates = vector_factory(coordinates_ptr, arg_sizes.stops);
+
+    let Some(metric) = DistanceMetric::from_code(metric) else {
+        return SolverStatus::UnknownMetric;
+    };
+
+    if !has_one_coordinate_per_stop(&stops, &coordinates) {
+        return SolverStatus::InvalidCoordinates;
+    }
+
+    let oracle: SharedDistanceOracle = Arc::new(CoordinateDistanceOracle::new(coordinates, metric));
+
+    solve(vehicles, stops, oracle, parameters, result)
+}
+
+fn has_one_coordinate_per_stop(stops: &[Stop], coordinates: &[Coordinate]) -> bool {
+    let mut coordinate_ids = HashSet::new();
+
+    coordinates.len() == stops.len()
+        && coordinates
+            .iter()
+            .all(|coordinate| coordinate_ids.insert(coordinate.id))
+        && stops.iter().all(|stop| coordinate_ids.contains(&stop.id))
+}
+
+unsafe fn solve(
+    vehicles: Vec<Vehicle>,
+    stops: Vec<Stop>,
+    oracle: SharedDistanceOracle,
+    parameters: GeneticAlgorithmParameters,
+    result: *mut u32,
+) -> SolverStatus {
+    if !stops.iter().any(|stop| stop.id == DEPOT_ID) {
+        return SolverStatus::MissingDepot;
+    }
+
+    let mut rng = thread_rng();
+
+    let route_service = RouteService::new(vehicles, &oracle, stops.clone());
 
     let mut genetic_solver = GeneticSolver::new(
         stops,
-        &distances,
+        &oracle,
         parameters.population_size,
         parameters.elite_size,
         parameters.mutation_rate,
@@ -52,4 +123,6 @@ pub unsafe extern "C" fn genetic_solver(
         genetic_solver.solution.result.get(&0).unwrap().to_vec(),
         result,
     );
+
+    SolverStatus::Solved
 }
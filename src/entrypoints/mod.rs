@@ -1,5 +1,6 @@
 pub mod genetic_solver;
 pub mod parameters;
+pub mod solver_status;
 mod lib_tests;
 mod c_interfaces;
 mod factories;
@@ -0,0 +1,9 @@
+#[repr(C)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[must_use]
+pub enum SolverStatus {
+    Solved = 0,
+    MissingDepot = 1,
+    UnknownMetric = 2,
+    InvalidCoordinates = 3,
+}
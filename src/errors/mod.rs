@@ -1 +1,3 @@
+pub mod simulation;
+pub mod solver;
 pub mod vehicle;
@@ -0,0 +1,28 @@
+use std::{error::Error, fmt};
+
+#[derive(Debug)]
+pub struct InvalidScenarioError {
+    pub line: usize,
+    description: &'static str,
+}
+
+impl InvalidScenarioError {
+    pub fn new(line: usize) -> InvalidScenarioError {
+        InvalidScenarioError {
+            line,
+            description: "The scenario could not be parsed",
+        }
+    }
+}
+
+impl fmt::Display for InvalidScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (line {})", self.description, self.line)
+    }
+}
+
+impl Error for InvalidScenarioError {
+    fn description(&self) -> &str {
+        self.description
+    }
+}
@@ -0,0 +1 @@
+pub mod invalid_scenario;
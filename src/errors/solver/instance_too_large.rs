@@ -0,0 +1,32 @@
+use std::{error::Error, fmt};
+
+#[derive(Debug)]
+pub struct InstanceTooLargeError {
+    description: &'static str,
+}
+
+impl Default for InstanceTooLargeError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InstanceTooLargeError {
+    pub fn new() -> InstanceTooLargeError {
+        InstanceTooLargeError {
+            description: "The instance has too many stops to be solved exactly",
+        }
+    }
+}
+
+impl fmt::Display for InstanceTooLargeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description)
+    }
+}
+
+impl Error for InstanceTooLargeError {
+    fn description(&self) -> &str {
+        self.description
+    }
+}
@@ -0,0 +1,32 @@
+use std::{error::Error, fmt};
+
+#[derive(Debug)]
+pub struct InvalidSolutionError {
+    description: &'static str,
+}
+
+impl Default for InvalidSolutionError {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InvalidSolutionError {
+    pub fn new() -> InvalidSolutionError {
+        InvalidSolutionError {
+            description: "The initial solution does not match the problem",
+        }
+    }
+}
+
+impl fmt::Display for InvalidSolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description)
+    }
+}
+
+impl Error for InvalidSolutionError {
+    fn description(&self) -> &str {
+        self.description
+    }
+}
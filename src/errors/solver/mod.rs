@@ -0,0 +1,2 @@
+pub mod instance_too_large;
+pub mod invalid_solution;
@@ -1,8 +1,10 @@
 pub mod domain;
+pub mod entrypoints;
 pub mod errors;
 pub mod local_search;
+pub mod lower_bound;
 pub mod services;
+pub mod simulation;
 pub mod solvers;
 pub mod stop_swapper;
 pub mod tests;
-pub mod entrypoints;
@@ -0,0 +1,131 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    services::distance::distance_service::DistanceService,
+};
+
+pub(crate) type Position = (usize, usize);
+
+#[derive(Copy, Clone, Debug)]
+pub struct Granularity {
+    pub neighbours: usize,
+    pub threshold: f64,
+}
+
+impl Granularity {
+    pub fn new(neighbours: usize, threshold: f64) -> Self {
+        Self {
+            neighbours,
+            threshold,
+        }
+    }
+}
+
+pub(crate) struct CandidateList {
+    neighbours: HashMap<u32, Vec<u32>>,
+}
+
+impl CandidateList {
+    pub(crate) fn new(
+        stops: &[Stop],
+        distance_service: &DistanceService,
+        granularity: Granularity,
+    ) -> Self {
+        let mut edges = HashSet::new();
+        let mut neighbours: HashMap<u32, Vec<u32>> = HashMap::new();
+
+        for stop in stops {
+            let nearest = distance_service
+                .get_k_nearest_stops(stop, granularity.neighbours, |_| true)
+                .into_iter()
+                .take_while(|neighbour| {
+                    distance_service
+                        .get_distance(stop, neighbour)
+                        .is_some_and(|distance| distance <= granularity.threshold)
+                });
+
+            for neighbour in nearest {
+                if edges.insert((stop.id, neighbour.id)) {
+                    neighbours.entry(stop.id).or_default().push(neighbour.id);
+                }
+
+                if edges.insert((neighbour.id, stop.id)) {
+                    neighbours.entry(neighbour.id).or_default().push(stop.id);
+                }
+            }
+        }
+
+        Self { neighbours }
+    }
+
+    pub(crate) fn from_granularity(
+        stops: &[Stop],
+        distance_service: &DistanceService,
+        granularity: Option<Granularity>,
+    ) -> Option<Self> {
+        Some(Self::new(stops, distance_service, granularity?))
+    }
+
+    #[cfg(test)]
+    pub(crate) fn is_candidate(&self, from: &Stop, to: &Stop) -> bool {
+        self.neighbours
+            .get(&from.id)
+            .is_some_and(|neighbours| neighbours.contains(&to.id))
+    }
+
+    /// Index pairs `(p, q)` with `p < q` whose stops form a candidate edge.
+    pub(crate) fn pairs(&self, stops: &[Stop]) -> Vec<(usize, usize)> {
+        let mut positions: HashMap<u32, Vec<usize>> = HashMap::new();
+
+        for (index, stop) in stops.iter().enumerate() {
+            positions.entry(stop.id).or_default().push(index);
+        }
+
+        let mut pairs = Vec::new();
+
+        for (first, stop) in stops.iter().enumerate() {
+            for neighbour in self.neighbours.get(&stop.id).into_iter().flatten() {
+                for &second in positions.get(neighbour).into_iter().flatten() {
+                    if first < second {
+                        pairs.push((first, second));
+                    }
+                }
+            }
+        }
+
+        pairs.sort_unstable();
+        pairs
+    }
+
+    /// Ordered `(route, index)` pairs in different routes whose stops form a candidate edge.
+    pub(crate) fn route_pairs(&self, routes: &[Route]) -> Vec<(Position, Position)> {
+        let mut positions: HashMap<u32, Vec<Position>> = HashMap::new();
+
+        for (route_index, route) in routes.iter().enumerate() {
+            for (index, stop) in route.stops.iter().enumerate() {
+                positions
+                    .entry(stop.id)
+                    .or_default()
+                    .push((route_index, index));
+            }
+        }
+
+        let mut pairs = Vec::new();
+
+        for (route_index, route) in routes.iter().enumerate() {
+            for (index, stop) in route.stops.iter().enumerate() {
+                for neighbour in self.neighbours.get(&stop.id).into_iter().flatten() {
+                    for &position in positions.get(neighbour).into_iter().flatten() {
+                        if position.0 != route_index {
+                            pairs.push(((route_index, index), position));
+                        }
+                    }
+                }
+            }
+        }
+
+        pairs.sort_unstable();
+        pairs
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{
+    domain::{route::Route, stop::Stop},
+    services::distance::{
+        distance_oracle::IntoDistanceOracle,
+        distance_service::{DistanceService, StopsMap},
+    },
+    solvers::solution::Solution,
+};
+
+use super::{
+    improvement_strategy::is_improvement, lin_kernighan::LinKernighanSearcher,
+    route_profile::RouteProfile,
+};
+
+pub const MAX_EXACT_STOPS: usize = 20;
+
+const FALLBACK_NEIGHBOURS: usize = 8;
+const FALLBACK_MAX_DEPTH: usize = 5;
+
+pub struct HeldKarpOptimizer {
+    stops: StopsMap,
+    max_exact_stops: usize,
+    fallback: LinKernighanSearcher,
+    distance_service: DistanceService,
+}
+
+impl HeldKarpOptimizer {
+    pub fn new(
+        stops: Vec<Stop>,
+        distances: impl IntoDistanceOracle,
+        max_exact_stops: usize,
+    ) -> Self {
+        let oracle = distances.into_oracle();
+
+        Self {
+            max_exact_stops: max_exact_stops.min(MAX_EXACT_STOPS),
+            fallback: LinKernighanSearcher::new(
+                stops.clone(),
+                &oracle,
+                FALLBACK_NEIGHBOURS,
+                FALLBACK_MAX_DEPTH,
+            ),
+            stops: stops.iter().map(|stop| (stop.id, *stop)).collect(),
+            distance_service: DistanceService::new(stops, &oracle),
+        }
+    }
+
+    fn distance_table(&self, route: &Route) -> Option<Vec<Vec<f64>>> {
+        route
+            .stops
+            .iter()
+            .map(|from| {
+                route
+                    .stops
+                    .iter()
+                    .map(|to| self.distance_service.get_distance(from, to))
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub(crate) fn optimal_order(&self, route: &Route) -> Option<(Vec<Stop>, f64)> {
+        let distances = self.distance_table(route)?;
+        let last_index = route.stops.len() - 1;
+        let interior = last_index - 1;
+
+        if interior > MAX_EXACT_STOPS {
+            return None;
+        }
+
+        let subsets = 1usize << interior;
+
+        let mut costs = vec![f64::INFINITY; subsets * interior];
+        let mut parents = vec![usize::MAX; subsets * interior];
+
+        for stop in 0..interior {
+            costs[(1 << stop) * interior + stop] = distances[0][stop + 1];
+        }
+
+        for subset in 1..subsets {
+            for last in (0..interior).filter(|last| subset & (1 << last) != 0) {
+                let cost = costs[subset * interior + last];
+
+                if cost == f64::INFINITY {
+                    continue;
+                }
+
+                for next in (0..interior).filter(|next| subset & (1 << next) == 0) {
+                    let state = (subset | (1 << next)) * interior + next;
+                    let new_cost = cost + distances[last + 1][next + 1];
+
+                    if new_cost < costs[state] {
+                        costs[state] = new_cost;
+                        parents[state] = last;
+                    }
+                }
+            }
+        }
+
+        let full_subset = subsets - 1;
+
+        let (mut last, total_cost) = (0..interior)
+            .map(|last| {
+                (
+                    last,
+                    costs[full_subset * interior + last] + distances[last + 1][last_index],
+                )
+            })
+            .min_by(|(_, cost1), (_, cost2)| cost1.partial_cmp(cost2).unwrap())?;
+
+        let mut order = Vec::with_capacity(interior);
+        let mut subset = full_subset;
+
+        while last != usize::MAX {
+            order.push(route.stops[last + 1]);
+
+            let parent = parents[subset * interior + last];
+            subset &= !(1 << last);
+            last = parent;
+        }
+
+        order.reverse();
+
+        Some((order, total_cost))
+    }
+
+    pub fn run(&self, route: &mut Route) -> Option<()> {
+        let last_index = route.stops.len().saturating_sub(1);
+
+        if last_index < 3 {
+            return Some(());
+        }
+
+        if last_index - 1 > self.max_exact_stops {
+            return self.fallback.run(route);
+        }
+
+        let profile = RouteProfile::new(route, &self.distance_service)?;
+        let current_cost = profile.segment_distance(&(0..route.stops.len()));
+
+        let (order, optimal_cost) = self.optimal_order(route)?;
+        let distance_change = optimal_cost - current_cost;
+
+        if is_improvement(distance_change) {
+            route
+                .replace_stops(1..last_index, order, distance_change)
+                .ok()?;
+        }
+
+        Some(())
+    }
+
+    pub fn optimize_solution(&self, solution: &mut Solution) -> Option<()> {
+        solution.improve_routes(&self.stops, &self.distance_service, |route| self.run(route))
+    }
+}
@@ -0,0 +1,11 @@
+const IMPROVEMENT_THRESHOLD: f64 = 1e-9;
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ImprovementStrategy {
+    FirstImprovement,
+    BestImprovement,
+}
+
+pub(crate) fn is_improvement(cost: f64) -> bool {
+    cost < -IMPROVEMENT_THRESHOLD
+}
@@ -0,0 +1,134 @@
+use std::ops::Range;
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    local_search::route_profile::RouteProfile,
+    services::distance::distance_service::DistanceService,
+};
+
+#[derive(Clone)]
+pub(crate) struct Segment {
+    pub(crate) route: usize,
+    pub(crate) range: Range<usize>,
+}
+
+impl Segment {
+    pub(crate) fn new(route: usize, range: Range<usize>) -> Self {
+        Self { route, range }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct InterRouteMove {
+    pub(crate) first: Segment,
+    pub(crate) second: Segment,
+    pub(crate) first_cost: f64,
+    pub(crate) second_cost: f64,
+}
+
+impl InterRouteMove {
+    fn connection_cost(
+        prev: &Stop,
+        segment: &[Stop],
+        segment_distance: f64,
+        next: &Stop,
+        distance_service: &DistanceService,
+    ) -> Option<f64> {
+        match (segment.first(), segment.last()) {
+            (Some(first), Some(last)) => Some(
+                distance_service.get_distance(prev, first)?
+                    + segment_distance
+                    + distance_service.get_distance(last, next)?,
+            ),
+            _ => distance_service.get_distance(prev, next),
+        }
+    }
+
+    fn exchange_cost(
+        routes: &[Route],
+        profiles: &[RouteProfile],
+        removed: &Segment,
+        inserted: &Segment,
+        distance_service: &DistanceService,
+    ) -> Option<f64> {
+        let stops = &routes[removed.route].stops;
+        let prev = stops.get(removed.range.start.checked_sub(1)?)?;
+        let next = stops.get(removed.range.end)?;
+
+        let current_cost = Self::connection_cost(
+            prev,
+            &stops[removed.range.clone()],
+            profiles[removed.route].segment_distance(&removed.range),
+            next,
+            distance_service,
+        )?;
+
+        let new_cost = Self::connection_cost(
+            prev,
+            &routes[inserted.route].stops[inserted.range.clone()],
+            profiles[inserted.route].segment_distance(&inserted.range),
+            next,
+            distance_service,
+        )?;
+
+        Some(new_cost - current_cost)
+    }
+
+    fn is_feasible(
+        routes: &[Route],
+        profiles: &[RouteProfile],
+        first: &Segment,
+        second: &Segment,
+    ) -> bool {
+        let first_load = profiles[first.route].segment_load(&first.range);
+        let second_load = profiles[second.route].segment_load(&second.range);
+
+        routes[first.route]
+            .vehicle
+            .can_replace_load(first_load, second_load)
+            && routes[second.route]
+                .vehicle
+                .can_replace_load(second_load, first_load)
+    }
+
+    pub(crate) fn evaluate(
+        first: Segment,
+        second: Segment,
+        routes: &[Route],
+        profiles: &[RouteProfile],
+        distance_service: &DistanceService,
+    ) -> Option<Self> {
+        if !Self::is_feasible(routes, profiles, &first, &second) {
+            return None;
+        }
+
+        let first_cost = Self::exchange_cost(routes, profiles, &first, &second, distance_service)?;
+        let second_cost = Self::exchange_cost(routes, profiles, &second, &first, distance_service)?;
+
+        Some(Self {
+            first,
+            second,
+            first_cost,
+            second_cost,
+        })
+    }
+
+    pub(crate) fn cost(&self) -> f64 {
+        self.first_cost + self.second_cost
+    }
+
+    pub(crate) fn apply(&self, routes: &mut [Route]) -> Option<()> {
+        let first_stops = routes[self.first.route].stops[self.first.range.clone()].to_vec();
+        let second_stops = routes[self.second.route].stops[self.second.range.clone()].to_vec();
+
+        routes[self.first.route]
+            .replace_stops(self.first.range.clone(), second_stops, self.first_cost)
+            .ok()?;
+
+        routes[self.second.route]
+            .replace_stops(self.second.range.clone(), first_stops, self.second_cost)
+            .ok()?;
+
+        Some(())
+    }
+}
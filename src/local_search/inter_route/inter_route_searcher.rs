@@ -0,0 +1,321 @@
+use std::{collections::BTreeSet, ops::Range};
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    local_search::{
+        candidate_list::{CandidateList, Granularity, Position},
+        improvement_strategy::is_improvement,
+        route_profile::RouteProfile,
+    },
+    services::distance::{distance_oracle::IntoDistanceOracle, distance_service::DistanceService},
+};
+
+use super::inter_route_move::{InterRouteMove, Segment};
+
+pub struct InterRouteSearcher {
+    distance_service: DistanceService,
+    max_segment_length: usize,
+    candidate_list: Option<CandidateList>,
+}
+
+impl InterRouteSearcher {
+    pub fn new(
+        stops: Vec<Stop>,
+        distances: impl IntoDistanceOracle,
+        max_segment_length: usize,
+        granularity: Option<Granularity>,
+    ) -> Self {
+        let distance_service = DistanceService::new(stops.clone(), distances);
+        let candidate_list =
+            CandidateList::from_granularity(&stops, &distance_service, granularity);
+
+        Self {
+            candidate_list,
+            distance_service,
+            max_segment_length,
+        }
+    }
+
+    fn route_pairs(routes: &[Route], ordered: bool) -> Vec<(usize, usize)> {
+        let is_valid = |route: &Route| route.stops.len() >= 2;
+
+        (0..routes.len())
+            .flat_map(|first| (0..routes.len()).map(move |second| (first, second)))
+            .filter(|(first, second)| first != second && (ordered || first < second))
+            .filter(|(first, second)| is_valid(&routes[*first]) && is_valid(&routes[*second]))
+            .collect()
+    }
+
+    fn evaluate(
+        &self,
+        first: Segment,
+        second: Segment,
+        routes: &[Route],
+        profiles: &[RouteProfile],
+    ) -> Option<InterRouteMove> {
+        InterRouteMove::evaluate(first, second, routes, profiles, &self.distance_service)
+    }
+
+    fn is_interior(route: &Route, range: &Range<usize>) -> bool {
+        range.start >= 1 && range.start <= range.end && range.end < route.stops.len()
+    }
+
+    fn ordered(first: Segment, second: Segment) -> (Segment, Segment) {
+        match first.route < second.route {
+            true => (first, second),
+            false => (second, first),
+        }
+    }
+
+    fn granular_moves(
+        &self,
+        routes: &[Route],
+        profiles: &[RouteProfile],
+        candidate_list: &CandidateList,
+        derive: impl Fn(Position, Position) -> Vec<(Segment, Segment)>,
+    ) -> Vec<InterRouteMove> {
+        let mut keys = BTreeSet::new();
+
+        for (from, to) in candidate_list.route_pairs(routes) {
+            for (first, second) in derive(from, to) {
+                if Self::is_interior(&routes[first.route], &first.range)
+                    && Self::is_interior(&routes[second.route], &second.range)
+                {
+                    keys.insert((
+                        (first.route, first.range.start, first.range.end),
+                        (second.route, second.range.start, second.range.end),
+                    ));
+                }
+            }
+        }
+
+        keys.into_iter()
+            .filter_map(
+                |((first, first_start, first_end), (second, second_start, second_end))| {
+                    self.evaluate(
+                        Segment::new(first, first_start..first_end),
+                        Segment::new(second, second_start..second_end),
+                        routes,
+                        profiles,
+                    )
+                },
+            )
+            .collect()
+    }
+
+    pub(crate) fn relocations(
+        &self,
+        routes: &[Route],
+        profiles: &[RouteProfile],
+    ) -> Vec<InterRouteMove> {
+        if let Some(candidate_list) = &self.candidate_list {
+            // Insert the stop right before or right after its neighbour.
+            return self.granular_moves(routes, profiles, candidate_list, |from, to| {
+                let stop = Segment::new(from.0, from.1..from.1 + 1);
+
+                vec![
+                    (stop.clone(), Segment::new(to.0, to.1..to.1)),
+                    (stop, Segment::new(to.0, to.1 + 1..to.1 + 1)),
+                ]
+            });
+        }
+
+        let mut moves = Vec::new();
+
+        for (origin, destination) in Self::route_pairs(routes, true) {
+            for stop_index in 1..routes[origin].stops.len() - 1 {
+                for position in 1..routes[destination].stops.len() {
+                    moves.extend(self.evaluate(
+                        Segment::new(origin, stop_index..stop_index + 1),
+                        Segment::new(destination, position..position),
+                        routes,
+                        profiles,
+                    ));
+                }
+            }
+        }
+
+        moves
+    }
+
+    pub(crate) fn exchanges(
+        &self,
+        routes: &[Route],
+        profiles: &[RouteProfile],
+    ) -> Vec<InterRouteMove> {
+        if let Some(candidate_list) = &self.candidate_list {
+            // Swap the neighbour with the stop right before or right after `from`.
+            return self.granular_moves(routes, profiles, candidate_list, |from, to| {
+                let neighbour = Segment::new(to.0, to.1..to.1 + 1);
+
+                [from.1.checked_sub(1), Some(from.1 + 1)]
+                    .into_iter()
+                    .flatten()
+                    .map(|index| {
+                        Self::ordered(Segment::new(from.0, index..index + 1), neighbour.clone())
+                    })
+                    .collect()
+            });
+        }
+
+        let mut moves = Vec::new();
+
+        for (first, second) in Self::route_pairs(routes, false) {
+            for first_index in 1..routes[first].stops.len() - 1 {
+                for second_index in 1..routes[second].stops.len() - 1 {
+                    moves.extend(self.evaluate(
+                        Segment::new(first, first_index..first_index + 1),
+                        Segment::new(second, second_index..second_index + 1),
+                        routes,
+                        profiles,
+                    ));
+                }
+            }
+        }
+
+        moves
+    }
+
+    pub(crate) fn tail_exchanges(
+        &self,
+        routes: &[Route],
+        profiles: &[RouteProfile],
+    ) -> Vec<InterRouteMove> {
+        if let Some(candidate_list) = &self.candidate_list {
+            // Cut right after `from` so that the tail starting at the neighbour follows it.
+            return self.granular_moves(routes, profiles, candidate_list, |from, to| {
+                let (Some(from_end), Some(to_end)) = (
+                    routes[from.0].stops.len().checked_sub(1),
+                    routes[to.0].stops.len().checked_sub(1),
+                ) else {
+                    return Vec::new();
+                };
+
+                let from_tail = from.1 + 1..from_end;
+                let to_tail = to.1..to_end;
+
+                if (from.1 == 0 && to.1 == 1) || (from_tail.is_empty() && to_tail.is_empty()) {
+                    return Vec::new();
+                }
+
+                vec![Self::ordered(
+                    Segment::new(from.0, from_tail),
+                    Segment::new(to.0, to_tail),
+                )]
+            });
+        }
+
+        let mut moves = Vec::new();
+
+        for (first, second) in Self::route_pairs(routes, false) {
+            let first_end = routes[first].stops.len() - 1;
+            let second_end = routes[second].stops.len() - 1;
+
+            for first_cut in 0..first_end {
+                for second_cut in 0..second_end {
+                    if first_cut == 0 && second_cut == 0 {
+                        continue;
+                    }
+
+                    let first_tail = first_cut + 1..first_end;
+                    let second_tail = second_cut + 1..second_end;
+
+                    if first_tail.is_empty() && second_tail.is_empty() {
+                        continue;
+                    }
+
+                    moves.extend(self.evaluate(
+                        Segment::new(first, first_tail),
+                        Segment::new(second, second_tail),
+                        routes,
+                        profiles,
+                    ));
+                }
+            }
+        }
+
+        moves
+    }
+
+    pub(crate) fn cross_exchanges(
+        &self,
+        routes: &[Route],
+        profiles: &[RouteProfile],
+    ) -> Vec<InterRouteMove> {
+        if let Some(candidate_list) = &self.candidate_list {
+            // Exchange the segment after `from` with the segment starting at the neighbour.
+            return self.granular_moves(routes, profiles, candidate_list, |from, to| {
+                let lengths = 1..=self.max_segment_length;
+
+                lengths
+                    .clone()
+                    .flat_map(|first| lengths.clone().map(move |second| (first, second)))
+                    .filter(|lengths| *lengths != (1, 1))
+                    .map(|(first_length, second_length)| {
+                        Self::ordered(
+                            Segment::new(from.0, from.1 + 1..from.1 + 1 + first_length),
+                            Segment::new(to.0, to.1..to.1 + second_length),
+                        )
+                    })
+                    .collect()
+            });
+        }
+
+        let mut moves = Vec::new();
+
+        for (first, second) in Self::route_pairs(routes, false) {
+            for first_length in 1..=self.max_segment_length {
+                for second_length in 1..=self.max_segment_length {
+                    if first_length == 1 && second_length == 1 {
+                        continue;
+                    }
+
+                    let first_starts = 1..routes[first].stops.len().saturating_sub(first_length);
+                    let second_starts = 1..routes[second].stops.len().saturating_sub(second_length);
+
+                    for first_start in first_starts {
+                        for second_start in second_starts.clone() {
+                            moves.extend(self.evaluate(
+                                Segment::new(first, first_start..first_start + first_length),
+                                Segment::new(second, second_start..second_start + second_length),
+                                routes,
+                                profiles,
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        moves
+    }
+
+    pub(crate) fn profiles(&self, routes: &[Route]) -> Option<Vec<RouteProfile>> {
+        routes
+            .iter()
+            .map(|route| RouteProfile::new(route, &self.distance_service))
+            .collect()
+    }
+
+    pub(crate) fn find_best_move(&self, routes: &[Route]) -> Option<InterRouteMove> {
+        let profiles = self.profiles(routes)?;
+
+        self.relocations(routes, &profiles)
+            .into_iter()
+            .chain(self.exchanges(routes, &profiles))
+            .chain(self.tail_exchanges(routes, &profiles))
+            .chain(self.cross_exchanges(routes, &profiles))
+            .min_by(|move1, move2| move1.cost().partial_cmp(&move2.cost()).unwrap())
+    }
+
+    pub fn run(&self, routes: &mut [Route]) -> Option<()> {
+        loop {
+            let best_move = match self.find_best_move(routes) {
+                Some(best_move) if is_improvement(best_move.cost()) => best_move,
+                _ => return Some(()),
+            };
+
+            best_move.apply(routes)?;
+        }
+    }
+}
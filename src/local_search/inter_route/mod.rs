@@ -0,0 +1,2 @@
+pub(crate) mod inter_route_move;
+pub mod inter_route_searcher;
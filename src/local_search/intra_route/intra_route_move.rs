@@ -0,0 +1,114 @@
+use std::ops::Range;
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    local_search::route_profile::RouteProfile,
+    services::distance::distance_service::DistanceService,
+};
+
+#[derive(Clone)]
+pub(crate) struct Piece {
+    pub(crate) range: Range<usize>,
+    pub(crate) reversed: bool,
+}
+
+impl Piece {
+    pub(crate) fn new(range: Range<usize>) -> Self {
+        Self {
+            range,
+            reversed: false,
+        }
+    }
+
+    pub(crate) fn reversed(range: Range<usize>) -> Self {
+        Self {
+            range,
+            reversed: true,
+        }
+    }
+
+    fn endpoints<'a>(&self, stops: &'a [Stop]) -> (&'a Stop, &'a Stop) {
+        let first = &stops[self.range.start];
+        let last = &stops[self.range.end - 1];
+
+        match self.reversed {
+            true => (last, first),
+            false => (first, last),
+        }
+    }
+
+    fn internal_distance(&self, profile: &RouteProfile) -> f64 {
+        match self.reversed {
+            true => profile.reversed_segment_distance(&self.range),
+            false => profile.segment_distance(&self.range),
+        }
+    }
+
+    fn stops(&self, stops: &[Stop]) -> Vec<Stop> {
+        let mut piece = stops[self.range.clone()].to_vec();
+
+        if self.reversed {
+            piece.reverse();
+        }
+
+        piece
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct IntraRouteMove {
+    pub(crate) range: Range<usize>,
+    pub(crate) pieces: Vec<Piece>,
+    pub(crate) cost: f64,
+}
+
+impl IntraRouteMove {
+    pub(crate) fn evaluate(
+        route: &Route,
+        profile: &RouteProfile,
+        range: Range<usize>,
+        pieces: Vec<Piece>,
+        distance_service: &DistanceService,
+    ) -> Option<Self> {
+        let stops = &route.stops;
+        let prev = stops.get(range.start.checked_sub(1)?)?;
+        let next = stops.get(range.end)?;
+
+        let current_cost = distance_service.get_distance(prev, &stops[range.start])?
+            + profile.segment_distance(&range)
+            + distance_service.get_distance(&stops[range.end - 1], next)?;
+
+        let mut new_cost = 0.0;
+        let mut last = prev;
+
+        for piece in pieces.iter() {
+            let (piece_first, piece_last) = piece.endpoints(stops);
+
+            new_cost += distance_service.get_distance(last, piece_first)?
+                + piece.internal_distance(profile);
+            last = piece_last;
+        }
+
+        new_cost += distance_service.get_distance(last, next)?;
+
+        Some(Self {
+            range,
+            pieces,
+            cost: new_cost - current_cost,
+        })
+    }
+
+    pub(crate) fn apply(&self, route: &mut Route) -> Option<()> {
+        let stops: Vec<Stop> = self
+            .pieces
+            .iter()
+            .flat_map(|piece| piece.stops(&route.stops))
+            .collect();
+
+        route
+            .replace_stops(self.range.clone(), stops, self.cost)
+            .ok()?;
+
+        Some(())
+    }
+}
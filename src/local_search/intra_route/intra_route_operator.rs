@@ -0,0 +1,45 @@
+use crate::{
+    domain::route::Route,
+    local_search::{
+        candidate_list::CandidateList,
+        improvement_strategy::{is_improvement, ImprovementStrategy},
+        route_profile::RouteProfile,
+    },
+    services::distance::distance_service::DistanceService,
+};
+
+use super::intra_route_move::IntraRouteMove;
+
+pub(crate) trait IntraRouteOperator {
+    fn distance_service(&self) -> &DistanceService;
+
+    fn strategy(&self) -> ImprovementStrategy;
+
+    fn candidate_list(&self) -> Option<&CandidateList>;
+
+    fn neighbourhood(&self, route: &Route, profile: &RouteProfile) -> Vec<IntraRouteMove>;
+
+    fn find_improvement(&self, route: &Route) -> Option<IntraRouteMove> {
+        let profile = RouteProfile::new(route, self.distance_service())?;
+
+        let mut improvements = self
+            .neighbourhood(route, &profile)
+            .into_iter()
+            .filter(|intra_route_move| is_improvement(intra_route_move.cost));
+
+        match self.strategy() {
+            ImprovementStrategy::FirstImprovement => improvements.next(),
+            ImprovementStrategy::BestImprovement => {
+                improvements.min_by(|move1, move2| move1.cost.partial_cmp(&move2.cost).unwrap())
+            }
+        }
+    }
+
+    fn run_to_convergence(&self, route: &mut Route) -> Option<()> {
+        while let Some(improvement) = self.find_improvement(route) {
+            improvement.apply(route)?;
+        }
+
+        Some(())
+    }
+}
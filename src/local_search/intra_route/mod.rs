@@ -0,0 +1,2 @@
+pub(crate) mod intra_route_move;
+pub(crate) mod intra_route_operator;
@@ -0,0 +1,225 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    ops::Range,
+};
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    services::distance::{
+        distance_oracle::IntoDistanceOracle,
+        distance_service::{DistanceService, StopsMap},
+    },
+    solvers::solution::Solution,
+};
+
+use super::{
+    improvement_strategy::is_improvement,
+    intra_route::intra_route_move::{IntraRouteMove, Piece},
+    route_profile::RouteProfile,
+};
+
+type Edge = (u32, u32);
+
+pub struct LinKernighanSearcher {
+    stops: StopsMap,
+    max_depth: usize,
+    neighbours: HashMap<u32, Vec<u32>>,
+    distance_service: DistanceService,
+}
+
+impl LinKernighanSearcher {
+    pub fn new(
+        stops: Vec<Stop>,
+        distances: impl IntoDistanceOracle,
+        number_of_neighbours: usize,
+        max_depth: usize,
+    ) -> Self {
+        let distance_service = DistanceService::new(stops.clone(), distances);
+
+        let neighbours = stops
+            .iter()
+            .map(|stop| {
+                let nearest = distance_service
+                    .get_k_nearest_stops(stop, number_of_neighbours, |neighbour| neighbour != stop)
+                    .iter()
+                    .map(|neighbour| neighbour.id)
+                    .collect();
+
+                (stop.id, nearest)
+            })
+            .collect();
+
+        Self {
+            max_depth,
+            neighbours,
+            distance_service,
+            stops: stops.iter().map(|stop| (stop.id, *stop)).collect(),
+        }
+    }
+
+    fn edge(from: &Stop, to: &Stop) -> Edge {
+        (from.id.min(to.id), from.id.max(to.id))
+    }
+
+    fn interior_positions(route: &Route) -> HashMap<u32, usize> {
+        let last_index = route.stops.len().saturating_sub(1);
+
+        route
+            .stops
+            .iter()
+            .enumerate()
+            .take(last_index)
+            .skip(1)
+            .map(|(index, stop)| (stop.id, index))
+            .collect()
+    }
+
+    fn reversal_range(anchor: usize, target: usize) -> Option<Range<usize>> {
+        if target > anchor + 1 {
+            return Some(anchor + 1..target + 1);
+        }
+
+        if target + 1 < anchor {
+            return Some(target..anchor);
+        }
+
+        None
+    }
+
+    fn removed_edges(route: &Route, range: &Range<usize>) -> [Edge; 2] {
+        let stops = &route.stops;
+
+        [
+            Self::edge(&stops[range.start - 1], &stops[range.start]),
+            Self::edge(&stops[range.end - 1], &stops[range.end]),
+        ]
+    }
+
+    fn added_edges(route: &Route, range: &Range<usize>) -> [Edge; 2] {
+        let stops = &route.stops;
+
+        [
+            Self::edge(&stops[range.start - 1], &stops[range.end - 1]),
+            Self::edge(&stops[range.start], &stops[range.end]),
+        ]
+    }
+
+    fn closing_distance(&self, route: &Route, anchor: usize, range: &Range<usize>) -> Option<f64> {
+        let stops = &route.stops;
+
+        match range.start > anchor {
+            true => self
+                .distance_service
+                .get_distance(&stops[range.start], &stops[range.end]),
+            false => self
+                .distance_service
+                .get_distance(&stops[range.start - 1], &stops[range.end - 1]),
+        }
+    }
+
+    fn find_step(
+        &self,
+        route: &Route,
+        anchor_id: u32,
+        gain: f64,
+        added_edges: &HashSet<Edge>,
+    ) -> Option<IntraRouteMove> {
+        let positions = Self::interior_positions(route);
+        let anchor = *positions.get(&anchor_id)?;
+        let profile = RouteProfile::new(route, &self.distance_service)?;
+
+        self.neighbours
+            .get(&anchor_id)?
+            .iter()
+            .filter_map(|neighbour| positions.get(neighbour))
+            .filter_map(|target| Self::reversal_range(anchor, *target))
+            .filter(|range| {
+                Self::removed_edges(route, range)
+                    .iter()
+                    .all(|edge| !added_edges.contains(edge))
+            })
+            .filter_map(|range| {
+                let closing_distance = self.closing_distance(route, anchor, &range)?;
+
+                let step = IntraRouteMove::evaluate(
+                    route,
+                    &profile,
+                    range.clone(),
+                    vec![Piece::reversed(range)],
+                    &self.distance_service,
+                )?;
+
+                Some((step, closing_distance))
+            })
+            .filter(|(step, closing_distance)| gain - step.cost + closing_distance > 0.0)
+            .map(|(step, _)| step)
+            .min_by(|step1, step2| step1.cost.partial_cmp(&step2.cost).unwrap())
+    }
+
+    fn improve_from(&self, route: &mut Route, anchor_id: u32) -> Option<Vec<u32>> {
+        let mut current = route.clone();
+        let mut gain = 0.0;
+        let mut best_gain = 0.0;
+        let mut added_edges: HashSet<Edge> = HashSet::new();
+        let mut touched_stops: Vec<u32> = Vec::new();
+        let mut best: Option<(Route, Vec<u32>)> = None;
+
+        for _ in 0..self.max_depth {
+            let step = match self.find_step(&current, anchor_id, gain, &added_edges) {
+                Some(step) => step,
+                None => break,
+            };
+
+            for edge in Self::added_edges(&current, &step.range) {
+                added_edges.insert(edge);
+                touched_stops.extend([edge.0, edge.1]);
+            }
+
+            step.apply(&mut current)?;
+            gain -= step.cost;
+
+            if is_improvement(best_gain - gain) {
+                best_gain = gain;
+                best = Some((current.clone(), touched_stops.clone()));
+            }
+        }
+
+        let (improved_route, touched_stops) = best?;
+        *route = improved_route;
+
+        Some(touched_stops)
+    }
+
+    pub fn run(&self, route: &mut Route) -> Option<()> {
+        let interior = route.stops.len().saturating_sub(2);
+        let mut active: VecDeque<u32> = route
+            .stops
+            .iter()
+            .skip(1)
+            .take(interior)
+            .map(|stop| stop.id)
+            .collect();
+        let mut is_active: HashSet<u32> = active.iter().cloned().collect();
+
+        while let Some(anchor_id) = active.pop_front() {
+            is_active.remove(&anchor_id);
+
+            let touched_stops = match self.improve_from(route, anchor_id) {
+                Some(touched_stops) => touched_stops,
+                None => continue,
+            };
+
+            for stop_id in touched_stops.into_iter().chain([anchor_id]) {
+                if self.stops.contains_key(&stop_id) && is_active.insert(stop_id) {
+                    active.push_back(stop_id);
+                }
+            }
+        }
+
+        Some(())
+    }
+
+    pub fn optimize_solution(&self, solution: &mut Solution) -> Option<()> {
+        solution.improve_routes(&self.stops, &self.distance_service, |route| self.run(route))
+    }
+}
@@ -1,2 +1,11 @@
+pub mod candidate_list;
+pub mod held_karp;
+pub mod improvement_strategy;
+pub mod inter_route;
+pub(crate) mod intra_route;
+pub mod lin_kernighan;
+pub mod or_opt;
+pub(crate) mod route_profile;
 mod tests;
+pub mod three_opt;
 pub mod two_opt;
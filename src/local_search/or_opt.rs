@@ -0,0 +1,130 @@
+use std::ops::Range;
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    services::distance::{distance_oracle::IntoDistanceOracle, distance_service::DistanceService},
+};
+
+use super::{
+    candidate_list::{CandidateList, Granularity},
+    improvement_strategy::ImprovementStrategy,
+    intra_route::{
+        intra_route_move::{IntraRouteMove, Piece},
+        intra_route_operator::IntraRouteOperator,
+    },
+    route_profile::RouteProfile,
+};
+
+const MAX_CHAIN_LENGTH: usize = 3;
+
+type OrOptMove = (usize, usize, usize);
+
+pub struct OrOptSearcher {
+    distance_service: DistanceService,
+    strategy: ImprovementStrategy,
+    candidate_list: Option<CandidateList>,
+}
+
+impl OrOptSearcher {
+    pub fn new(
+        stops: Vec<Stop>,
+        distances: impl IntoDistanceOracle,
+        strategy: ImprovementStrategy,
+        granularity: Option<Granularity>,
+    ) -> Self {
+        let distance_service = DistanceService::new(stops.clone(), distances);
+        let candidate_list =
+            CandidateList::from_granularity(&stops, &distance_service, granularity);
+
+        Self {
+            strategy,
+            candidate_list,
+            distance_service,
+        }
+    }
+
+    pub fn run(&self, route: &mut Route) -> Option<()> {
+        self.run_to_convergence(route)
+    }
+
+    fn relocation(start: usize, end: usize, position: usize) -> Option<(Range<usize>, Vec<Piece>)> {
+        match position {
+            position if position < start => Some((
+                position..end,
+                vec![Piece::new(start..end), Piece::new(position..start)],
+            )),
+            position if position > end => Some((
+                start..position,
+                vec![Piece::new(end..position), Piece::new(start..end)],
+            )),
+            _ => None,
+        }
+    }
+
+    fn granular_moves(route: &Route, candidate_list: &CandidateList) -> Vec<OrOptMove> {
+        let last_index = route.stops.len().saturating_sub(1);
+        let mut moves = Vec::new();
+
+        // Each move is generated from the new edge entering the head of the chain.
+        for (first, second) in candidate_list.pairs(&route.stops) {
+            for (predecessor, start) in [(first, second), (second, first)] {
+                let position = predecessor + 1;
+
+                for chain_length in 1..=MAX_CHAIN_LENGTH {
+                    let end = start + chain_length;
+
+                    if start >= 1 && end <= last_index && position <= last_index {
+                        moves.push((start, end, position));
+                    }
+                }
+            }
+        }
+
+        moves
+    }
+
+    fn all_moves(route: &Route) -> Vec<OrOptMove> {
+        let last_index = route.stops.len().saturating_sub(1);
+        let mut moves = Vec::new();
+
+        for chain_length in 1..=MAX_CHAIN_LENGTH {
+            for start in 1..last_index.saturating_sub(chain_length - 1) {
+                for position in 1..=last_index {
+                    moves.push((start, start + chain_length, position));
+                }
+            }
+        }
+
+        moves
+    }
+}
+
+impl IntraRouteOperator for OrOptSearcher {
+    fn distance_service(&self) -> &DistanceService {
+        &self.distance_service
+    }
+
+    fn strategy(&self) -> ImprovementStrategy {
+        self.strategy
+    }
+
+    fn candidate_list(&self) -> Option<&CandidateList> {
+        self.candidate_list.as_ref()
+    }
+
+    fn neighbourhood(&self, route: &Route, profile: &RouteProfile) -> Vec<IntraRouteMove> {
+        let moves = match self.candidate_list() {
+            Some(candidate_list) => Self::granular_moves(route, candidate_list),
+            None => Self::all_moves(route),
+        };
+
+        moves
+            .into_iter()
+            .filter_map(|(start, end, position)| {
+                let (range, pieces) = Self::relocation(start, end, position)?;
+
+                IntraRouteMove::evaluate(route, profile, range, pieces, &self.distance_service)
+            })
+            .collect()
+    }
+}
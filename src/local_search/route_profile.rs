@@ -0,0 +1,58 @@
+use std::ops::Range;
+
+use crate::{domain::route::Route, services::distance::distance_service::DistanceService};
+
+pub(crate) struct RouteProfile {
+    loads: Vec<u32>,
+    distances: Vec<f64>,
+    reversed_distances: Vec<f64>,
+}
+
+impl RouteProfile {
+    pub(crate) fn new(route: &Route, distance_service: &DistanceService) -> Option<Self> {
+        let mut loads = vec![0];
+        let mut distances = vec![0.0];
+        let mut reversed_distances = vec![0.0];
+
+        for (index, stop) in route.stops.iter().enumerate() {
+            loads.push(loads[index] + stop.usage);
+
+            if index > 0 {
+                let previous = &route.stops[index - 1];
+
+                distances
+                    .push(distances[index - 1] + distance_service.get_distance(previous, stop)?);
+                reversed_distances.push(
+                    reversed_distances[index - 1]
+                        + distance_service.get_distance(stop, previous)?,
+                );
+            }
+        }
+
+        Some(Self {
+            loads,
+            distances,
+            reversed_distances,
+        })
+    }
+
+    pub(crate) fn segment_load(&self, segment: &Range<usize>) -> u32 {
+        self.loads[segment.end] - self.loads[segment.start]
+    }
+
+    pub(crate) fn segment_distance(&self, segment: &Range<usize>) -> f64 {
+        if segment.is_empty() {
+            return 0.0;
+        }
+
+        self.distances[segment.end - 1] - self.distances[segment.start]
+    }
+
+    pub(crate) fn reversed_segment_distance(&self, segment: &Range<usize>) -> f64 {
+        if segment.is_empty() {
+            return 0.0;
+        }
+
+        self.reversed_distances[segment.end - 1] - self.reversed_distances[segment.start]
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::BTreeSet;
+
+use rstest::rstest;
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    local_search::{
+        candidate_list::{CandidateList, Granularity},
+        improvement_strategy::ImprovementStrategy,
+        inter_route::{inter_route_move::InterRouteMove, inter_route_searcher::InterRouteSearcher},
+        or_opt::OrOptSearcher,
+        route_profile::RouteProfile,
+        three_opt::ThreeOptSearcher,
+        two_opt::TwoOptSearcher,
+    },
+    services::distance::distance_service::{DistanceMatrix, DistanceService},
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
+use crate::tests::fixtures::services_fixture::distance_service;
+use crate::tests::fixtures::stops_fixture::{stops, stops_with_crossings};
+
+#[rstest]
+fn candidate_list_keeps_nearest_neighbours(distance_service: DistanceService, stops: Vec<Stop>) {
+    let candidate_list =
+        CandidateList::new(&stops, &distance_service, Granularity::new(2, f64::MAX));
+
+    assert!(candidate_list.is_candidate(&stops[1], &stops[3]));
+    assert!(candidate_list.is_candidate(&stops[3], &stops[1]));
+    assert!(!candidate_list.is_candidate(&stops[1], &stops[2]));
+}
+
+#[rstest]
+fn candidate_list_respects_threshold(distance_service: DistanceService, stops: Vec<Stop>) {
+    let candidate_list = CandidateList::new(&stops, &distance_service, Granularity::new(4, 2.5));
+
+    assert!(candidate_list.is_candidate(&stops[2], &stops[3]));
+    assert!(!candidate_list.is_candidate(&stops[1], &stops[3]));
+}
+
+#[rstest]
+fn depot_edges_follow_granularity(distance_service: DistanceService, stops: Vec<Stop>) {
+    let candidate_list =
+        CandidateList::new(&stops, &distance_service, Granularity::new(1, f64::MAX));
+
+    assert!(candidate_list.is_candidate(&stops[0], &stops[4]));
+    assert!(candidate_list.is_candidate(&stops[2], &stops[0]));
+    assert!(!candidate_list.is_candidate(&stops[3], &stops[0]));
+}
+
+#[rstest]
+fn candidate_pairs_follow_route_positions(
+    distance_service: DistanceService,
+    stops: Vec<Stop>,
+    stops_with_crossings: Vec<Stop>,
+) {
+    let candidate_list =
+        CandidateList::new(&stops, &distance_service, Granularity::new(1, f64::MAX));
+
+    assert_eq!(
+        candidate_list.pairs(&stops_with_crossings),
+        vec![(0, 2), (0, 3), (0, 4), (1, 4), (2, 5), (3, 5), (4, 5)]
+    );
+}
+
+#[rstest]
+fn empty_candidate_list_generates_no_moves(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_factory: RouteFactory,
+    stops_with_crossings: Vec<Stop>,
+) {
+    let two_opt = TwoOptSearcher::new(
+        stops,
+        &distances,
+        ImprovementStrategy::BestImprovement,
+        Some(Granularity::new(2, 0.0)),
+    );
+
+    let mut route = route_factory(stops_with_crossings);
+    let initial_distance = route.total_distance();
+
+    two_opt.run(&mut route).unwrap();
+
+    assert_eq!(route.total_distance(), initial_distance);
+}
+
+type Neighbourhood = fn(&InterRouteSearcher, &[Route], &[RouteProfile]) -> Vec<InterRouteMove>;
+
+fn move_keys(moves: Vec<InterRouteMove>) -> BTreeSet<(usize, usize, usize, usize, usize, usize)> {
+    moves
+        .iter()
+        .map(|inter_route_move| {
+            let (first, second) = (&inter_route_move.first, &inter_route_move.second);
+
+            (
+                first.route,
+                first.range.start,
+                first.range.end,
+                second.route,
+                second.range.start,
+                second.range.end,
+            )
+        })
+        .collect()
+}
+
+#[rstest]
+fn granular_inter_route_moves_are_a_subset(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_factory: RouteFactory,
+) {
+    let routes = vec![
+        route_factory(vec![stops[0], stops[2], stops[1], stops[0]]),
+        route_factory(vec![stops[0], stops[3], stops[0]]),
+    ];
+
+    let searcher = |granularity| InterRouteSearcher::new(stops.clone(), &distances, 2, granularity);
+    let (full, dense, sparse) = (
+        searcher(None),
+        searcher(Some(Granularity::new(4, f64::MAX))),
+        searcher(Some(Granularity::new(1, 1.0))),
+    );
+
+    let profiles = full.profiles(&routes).unwrap();
+
+    let neighbourhoods: [Neighbourhood; 4] = [
+        InterRouteSearcher::relocations,
+        InterRouteSearcher::exchanges,
+        InterRouteSearcher::tail_exchanges,
+        InterRouteSearcher::cross_exchanges,
+    ];
+
+    let (mut full_count, mut sparse_count) = (0, 0);
+
+    for neighbourhood in neighbourhoods {
+        let full_moves = move_keys(neighbourhood(&full, &routes, &profiles));
+        let dense_moves = move_keys(neighbourhood(&dense, &routes, &profiles));
+        let sparse_moves = move_keys(neighbourhood(&sparse, &routes, &profiles));
+
+        assert!(!dense_moves.is_empty());
+        assert!(dense_moves.is_subset(&full_moves));
+        assert!(sparse_moves.is_subset(&dense_moves));
+
+        full_count += full_moves.len();
+        sparse_count += sparse_moves.len();
+    }
+
+    assert!(sparse_count < full_count);
+}
+
+#[rstest]
+fn granular_search_improves_route(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_factory: RouteFactory,
+    stops_with_crossings: Vec<Stop>,
+) {
+    let two_opt = TwoOptSearcher::new(
+        stops,
+        &distances,
+        ImprovementStrategy::BestImprovement,
+        Some(Granularity::new(2, f64::MAX)),
+    );
+
+    let mut route = route_factory(stops_with_crossings);
+    let initial_distance = route.total_distance();
+
+    two_opt.run(&mut route).unwrap();
+
+    assert!(route.total_distance() < initial_distance);
+    assert_eq!(route.stops.len(), 6);
+}
+
+#[rstest]
+fn granular_three_opt_and_or_opt_improve_route(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_factory: RouteFactory,
+    stops_with_crossings: Vec<Stop>,
+) {
+    let granularity = Some(Granularity::new(2, f64::MAX));
+    let strategy = ImprovementStrategy::BestImprovement;
+
+    let three_opt = ThreeOptSearcher::new(stops.clone(), &distances, strategy, granularity);
+    let or_opt = OrOptSearcher::new(stops, &distances, strategy, granularity);
+
+    let mut three_opt_route = route_factory(stops_with_crossings.clone());
+    let mut or_opt_route = route_factory(stops_with_crossings);
+    let initial_distance = three_opt_route.total_distance();
+
+    three_opt.run(&mut three_opt_route).unwrap();
+    or_opt.run(&mut or_opt_route).unwrap();
+
+    for route in [three_opt_route, or_opt_route] {
+        assert!(route.total_distance() < initial_distance);
+        assert_eq!(route.stops.len(), 6);
+        assert_eq!(route.stops.first().unwrap().id, 0);
+        assert_eq!(route.stops.last().unwrap().id, 0);
+    }
+}
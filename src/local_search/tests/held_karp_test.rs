@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use rstest::rstest;
+
+use crate::domain::{route::Route, stop::Stop, vehicle::Vehicle};
+use crate::local_search::held_karp::HeldKarpOptimizer;
+use crate::services::distance::distance_service::DistanceMatrix;
+use crate::solvers::solution::Solution;
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::held_karp::held_karp;
+use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
+use crate::tests::fixtures::stops_fixture::{stops, stops_with_crossings};
+
+#[rstest]
+fn can_find_optimal_order(
+    held_karp: HeldKarpOptimizer,
+    route_factory: RouteFactory,
+    stops_with_crossings: Vec<Stop>,
+) {
+    let route = route_factory(stops_with_crossings);
+
+    let (order, cost) = held_karp.optimal_order(&route).unwrap();
+    let ids: Vec<u32> = order.iter().map(|stop| stop.id).collect();
+
+    assert_eq!(cost, 9.5);
+    assert!(ids == vec![4, 2, 3, 1] || ids == vec![1, 3, 2, 4]);
+}
+
+#[rstest]
+fn can_optimize_route(
+    held_karp: HeldKarpOptimizer,
+    route_factory: RouteFactory,
+    stops_with_crossings: Vec<Stop>,
+) {
+    let mut route = route_factory(stops_with_crossings);
+
+    held_karp.run(&mut route).unwrap();
+
+    assert_eq!(route.stops.first().unwrap().id, 0);
+    assert_eq!(route.stops.last().unwrap().id, 0);
+    assert_eq!(route.stops.len(), 6);
+    assert_eq!(route.total_distance(), 9.5);
+}
+
+#[rstest]
+fn falls_back_to_heuristic_on_long_routes(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_factory: RouteFactory,
+    stops_with_crossings: Vec<Stop>,
+) {
+    let held_karp = HeldKarpOptimizer::new(stops, &distances, 2);
+    let mut route = route_factory(stops_with_crossings);
+
+    held_karp.run(&mut route).unwrap();
+
+    assert_eq!(route.stops.len(), 6);
+    assert!(route.total_distance() < 19.0);
+}
+
+#[rstest]
+fn exact_threshold_is_clamped() {
+    let number_of_stops = 30;
+    let stops: Vec<Stop> = (0..number_of_stops).map(|id| Stop::new(id, 0)).collect();
+    let distances: DistanceMatrix = (0..number_of_stops)
+        .flat_map(|from| (0..number_of_stops).map(move |to| (from, to)))
+        .filter(|(from, to)| from != to)
+        .map(|(from, to)| ((from, to), from.abs_diff(to) as f64))
+        .collect();
+
+    let held_karp = HeldKarpOptimizer::new(stops.clone(), &distances, 64);
+
+    let mut route = Route::new(Vehicle::new(0, 100));
+    route.add_stop(stops[0], 0.0).unwrap();
+
+    for (index, stop) in stops.iter().enumerate().skip(1).rev() {
+        route
+            .add_stop(*stop, if index == 29 { 29.0 } else { 1.0 })
+            .unwrap();
+    }
+
+    route.add_stop(stops[0], 1.0).unwrap();
+
+    assert!(held_karp.optimal_order(&route).is_none());
+
+    held_karp.run(&mut route).unwrap();
+
+    assert_eq!(route.stops.len(), 31);
+    assert_eq!(route.total_distance(), 58.0);
+}
+
+#[rstest]
+fn can_polish_solution(held_karp: HeldKarpOptimizer) {
+    let mut solution = Solution {
+        total_distance: 23.0,
+        result: HashMap::from([(0, vec![0, 3, 4, 1, 2, 0]), (1, vec![0, 1, 0])]),
+    };
+
+    held_karp.optimize_solution(&mut solution).unwrap();
+
+    assert_eq!(solution.total_distance, 13.5);
+    assert_eq!(solution.result.get(&1).unwrap(), &vec![0, 1, 0]);
+}
+
+#[rstest]
+fn keeps_solution_when_a_route_fails(held_karp: HeldKarpOptimizer) {
+    let result = HashMap::from([(0, vec![0, 3, 4, 1, 2, 0]), (1, vec![0, 99, 0])]);
+    let mut solution = Solution {
+        total_distance: 23.0,
+        result: result.clone(),
+    };
+
+    assert!(held_karp.optimize_solution(&mut solution).is_none());
+
+    assert_eq!(solution.total_distance, 23.0);
+    assert_eq!(solution.result, result);
+}
@@ -0,0 +1,130 @@
+use rstest::rstest;
+
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    local_search::inter_route::inter_route_searcher::InterRouteSearcher,
+    services::distance::distance_service::DistanceService,
+};
+
+use crate::tests::fixtures::inter_route::inter_route_searcher;
+use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
+use crate::tests::fixtures::services_fixture::distance_service;
+use crate::tests::fixtures::stops_fixture::stops;
+
+fn calculate_distance(route: &Route, distance_service: &DistanceService) -> f64 {
+    route
+        .stops
+        .windows(2)
+        .map(|window| {
+            distance_service
+                .get_distance(&window[0], &window[1])
+                .unwrap()
+        })
+        .sum()
+}
+
+fn build_route(stops: &[Stop], capacity: u32, distance_service: &DistanceService) -> Route {
+    let mut route = Route::new(Vehicle::new(0, capacity));
+
+    route.add_stop(stops[0], 0.0).unwrap();
+    for window in stops.windows(2) {
+        let distance = distance_service
+            .get_distance(&window[0], &window[1])
+            .unwrap();
+        route.add_stop(window[1], distance).unwrap();
+    }
+
+    route
+}
+
+#[rstest]
+fn can_relocate_stop_between_routes(
+    inter_route_searcher: InterRouteSearcher,
+    route_factory: RouteFactory,
+    stops: Vec<Stop>,
+) {
+    let mut routes = vec![
+        route_factory(vec![stops[0], stops[2], stops[3], stops[0]]),
+        route_factory(vec![stops[0], stops[1], stops[0]]),
+    ];
+
+    inter_route_searcher.run(&mut routes).unwrap();
+
+    let total_distance: f64 = routes.iter().map(|route| route.total_distance()).sum();
+
+    assert_eq!(total_distance, 8.0);
+    assert_eq!(routes[0].stops.len() + routes[1].stops.len(), 7);
+}
+
+#[rstest]
+fn cannot_overload_vehicles(
+    inter_route_searcher: InterRouteSearcher,
+    distance_service: DistanceService,
+) {
+    let stops = [
+        Stop::new(0, 0),
+        Stop::new(1, 10),
+        Stop::new(2, 10),
+        Stop::new(3, 10),
+    ];
+
+    let mut routes = vec![
+        build_route(
+            &[stops[0], stops[2], stops[3], stops[0]],
+            25,
+            &distance_service,
+        ),
+        build_route(&[stops[0], stops[1], stops[0]], 25, &distance_service),
+    ];
+
+    inter_route_searcher.run(&mut routes).unwrap();
+
+    let total_distance: f64 = routes.iter().map(|route| route.total_distance()).sum();
+
+    assert_eq!(total_distance, 10.0);
+    assert_eq!(routes[0].vehicle.usage(), 20);
+    assert_eq!(routes[1].vehicle.usage(), 10);
+}
+
+#[rstest]
+fn move_costs_are_consistent(
+    inter_route_searcher: InterRouteSearcher,
+    distance_service: DistanceService,
+    stops: Vec<Stop>,
+) {
+    let routes = vec![
+        build_route(
+            &[stops[0], stops[2], stops[1], stops[0]],
+            200,
+            &distance_service,
+        ),
+        build_route(
+            &[stops[0], stops[3], stops[4], stops[0]],
+            200,
+            &distance_service,
+        ),
+    ];
+
+    let profiles = inter_route_searcher.profiles(&routes).unwrap();
+
+    let moves = inter_route_searcher
+        .relocations(&routes, &profiles)
+        .into_iter()
+        .chain(inter_route_searcher.exchanges(&routes, &profiles))
+        .chain(inter_route_searcher.tail_exchanges(&routes, &profiles))
+        .chain(inter_route_searcher.cross_exchanges(&routes, &profiles));
+
+    for inter_route_move in moves {
+        let mut moved_routes = routes.clone();
+        inter_route_move.apply(&mut moved_routes).unwrap();
+
+        for route in moved_routes.iter() {
+            assert_eq!(route.stops.first().unwrap().id, 0);
+            assert_eq!(route.stops.last().unwrap().id, 0);
+            assert_eq!(
+                route.total_distance(),
+                calculate_distance(route, &distance_service)
+            );
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use rstest::rstest;
+
+use crate::domain::{route::Route, stop::Stop};
+use crate::local_search::lin_kernighan::LinKernighanSearcher;
+use crate::solvers::solution::Solution;
+
+use crate::tests::fixtures::lin_kernighan::lin_kernighan;
+use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
+use crate::tests::fixtures::stops_fixture::stops_with_crossings;
+
+#[rstest]
+fn can_optimize_route(
+    lin_kernighan: LinKernighanSearcher,
+    route_factory: RouteFactory,
+    stops_with_crossings: Vec<Stop>,
+) {
+    let mut route = route_factory(stops_with_crossings);
+
+    lin_kernighan.run(&mut route).unwrap();
+
+    assert_eq!(route.stops.first().unwrap().id, 0);
+    assert_eq!(route.stops.last().unwrap().id, 0);
+    assert_eq!(route.stops.len(), 6);
+    assert_eq!(route.total_distance(), 9.5);
+}
+
+#[rstest]
+fn can_optimize_every_route_of_a_solution(lin_kernighan: LinKernighanSearcher) {
+    let mut solution = Solution {
+        total_distance: 23.0,
+        result: HashMap::from([(0, vec![0, 3, 4, 1, 2, 0]), (1, vec![0, 1, 0])]),
+    };
+
+    lin_kernighan.optimize_solution(&mut solution).unwrap();
+
+    assert_eq!(solution.total_distance, 13.5);
+    assert_eq!(solution.result.get(&0).unwrap().len(), 6);
+    assert_eq!(solution.result.get(&1).unwrap(), &vec![0, 1, 0]);
+}
+
+#[rstest]
+fn optimized_route_is_deterministic(
+    lin_kernighan: LinKernighanSearcher,
+    route_factory: RouteFactory,
+    stops_with_crossings: Vec<Stop>,
+) {
+    let mut first_route = route_factory(stops_with_crossings.clone());
+    lin_kernighan.run(&mut first_route).unwrap();
+
+    for _ in 0..10 {
+        let mut route = route_factory(stops_with_crossings.clone());
+        lin_kernighan.run(&mut route).unwrap();
+
+        let ids = |route: &Route| route.stops.iter().map(|stop| stop.id).collect::<Vec<u32>>();
+
+        assert_eq!(ids(&route), ids(&first_route));
+    }
+}
@@ -1,2 +1,20 @@
+#[cfg(test)]
+pub mod candidate_list_test;
+
+#[cfg(test)]
+pub mod held_karp_test;
+
+#[cfg(test)]
+pub mod inter_route_test;
+
+#[cfg(test)]
+pub mod lin_kernighan_test;
+
+#[cfg(test)]
+pub mod or_opt_test;
+
+#[cfg(test)]
+pub mod three_opt_test;
+
 #[cfg(test)]
 pub mod two_opt_test;
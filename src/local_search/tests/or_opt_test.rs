@@ -0,0 +1,36 @@
+use rstest::rstest;
+
+use crate::domain::stop::Stop;
+use crate::local_search::or_opt::OrOptSearcher;
+
+use crate::tests::fixtures::or_opt::or_opt;
+use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
+use crate::tests::fixtures::stops_fixture::stops;
+
+#[rstest]
+fn can_move_chain_of_stops(or_opt: OrOptSearcher, route_factory: RouteFactory, stops: Vec<Stop>) {
+    let mut route = route_factory(vec![stops[0], stops[2], stops[1], stops[3], stops[0]]);
+
+    or_opt.run(&mut route).unwrap();
+
+    let ids: Vec<u32> = route.stops.iter().map(|stop| stop.id).collect();
+
+    assert_eq!(ids, vec![0, 1, 3, 2, 0]);
+    assert_eq!(route.total_distance(), 8.0);
+}
+
+#[rstest]
+fn does_not_change_optimal_route(
+    or_opt: OrOptSearcher,
+    route_factory: RouteFactory,
+    stops: Vec<Stop>,
+) {
+    let mut route = route_factory(vec![stops[0], stops[2], stops[3], stops[1], stops[0]]);
+
+    or_opt.run(&mut route).unwrap();
+
+    let ids: Vec<u32> = route.stops.iter().map(|stop| stop.id).collect();
+
+    assert_eq!(ids, vec![0, 2, 3, 1, 0]);
+    assert_eq!(route.total_distance(), 8.0);
+}
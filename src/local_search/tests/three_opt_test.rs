@@ -0,0 +1,24 @@
+use rstest::rstest;
+
+use crate::domain::stop::Stop;
+use crate::local_search::three_opt::ThreeOptSearcher;
+
+use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
+use crate::tests::fixtures::stops_fixture::stops_with_crossings;
+use crate::tests::fixtures::three_opt::three_opt;
+
+#[rstest]
+fn can_optimize_route(
+    three_opt: ThreeOptSearcher,
+    route_factory: RouteFactory,
+    stops_with_crossings: Vec<Stop>,
+) {
+    let mut route = route_factory(stops_with_crossings);
+
+    three_opt.run(&mut route).unwrap();
+
+    assert_eq!(route.stops.first().unwrap().id, 0);
+    assert_eq!(route.stops.last().unwrap().id, 0);
+    assert_eq!(route.stops.len(), 6);
+    assert_eq!(route.total_distance(), 9.5);
+}
@@ -1,14 +1,16 @@
 use crate::stop_swapper::path::Path;
 use crate::{
-    local_search::two_opt::TwoOptSearcher, services::distance::distance_service::DistanceService,
+    local_search::{improvement_strategy::ImprovementStrategy, two_opt::TwoOptSearcher},
+    services::distance::distance_service::{DistanceMatrix, DistanceService},
 };
 use rstest::rstest;
 
 use crate::domain::stop::Stop;
 
+use crate::tests::fixtures::distances_fixture::distances;
 use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
 use crate::tests::fixtures::services_fixture::distance_service;
-use crate::tests::fixtures::stops_fixture::stops_with_crossings;
+use crate::tests::fixtures::stops_fixture::{stops, stops_with_crossings};
 use crate::tests::fixtures::two_opt::two_opt;
 
 #[rstest]
@@ -31,12 +33,33 @@ fn can_optimize_route(
 
     two_opt.run(&mut route).unwrap();
 
-    assert_eq!(route.stops.get(0).unwrap().id, 0);
-    assert_eq!(route.stops.get(1).unwrap().id, 2);
-    assert_eq!(route.stops.get(2).unwrap().id, 3);
-    assert_eq!(route.stops.get(3).unwrap().id, 1);
-    assert_eq!(route.stops.get(4).unwrap().id, 4);
+    assert_eq!(route.stops.first().unwrap().id, 0);
+    assert_eq!(route.stops.get(1).unwrap().id, 4);
+    assert_eq!(route.stops.get(2).unwrap().id, 2);
+    assert_eq!(route.stops.get(3).unwrap().id, 3);
+    assert_eq!(route.stops.get(4).unwrap().id, 1);
     assert_eq!(route.stops.get(5).unwrap().id, 0);
 
-    assert_eq!(route.total_distance(), 11.5);
+    assert_eq!(route.total_distance(), 9.5);
+}
+
+#[rstest]
+fn first_improvement_reaches_local_optimum(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_factory: RouteFactory,
+    stops_with_crossings: Vec<Stop>,
+) {
+    let two_opt = TwoOptSearcher::new(
+        stops,
+        &distances,
+        ImprovementStrategy::FirstImprovement,
+        None,
+    );
+    let mut route = route_factory(stops_with_crossings);
+
+    two_opt.run(&mut route).unwrap();
+
+    assert_eq!(route.stops.len(), 6);
+    assert_eq!(route.total_distance(), 9.5);
 }
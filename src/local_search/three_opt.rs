@@ -0,0 +1,138 @@
+use std::ops::Range;
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    services::distance::{distance_oracle::IntoDistanceOracle, distance_service::DistanceService},
+};
+
+use super::{
+    candidate_list::{CandidateList, Granularity},
+    improvement_strategy::ImprovementStrategy,
+    intra_route::{
+        intra_route_move::{IntraRouteMove, Piece},
+        intra_route_operator::IntraRouteOperator,
+    },
+    route_profile::RouteProfile,
+};
+
+const RECONNECTIONS: usize = 4;
+
+type ThreeOptMove = (usize, usize, usize, usize);
+
+pub struct ThreeOptSearcher {
+    distance_service: DistanceService,
+    strategy: ImprovementStrategy,
+    candidate_list: Option<CandidateList>,
+}
+
+impl ThreeOptSearcher {
+    pub fn new(
+        stops: Vec<Stop>,
+        distances: impl IntoDistanceOracle,
+        strategy: ImprovementStrategy,
+        granularity: Option<Granularity>,
+    ) -> Self {
+        let distance_service = DistanceService::new(stops.clone(), distances);
+        let candidate_list =
+            CandidateList::from_granularity(&stops, &distance_service, granularity);
+
+        Self {
+            strategy,
+            candidate_list,
+            distance_service,
+        }
+    }
+
+    pub fn run(&self, route: &mut Route) -> Option<()> {
+        self.run_to_convergence(route)
+    }
+
+    fn reconnection(first: Range<usize>, second: Range<usize>, kind: usize) -> Vec<Piece> {
+        match kind {
+            0 => vec![Piece::new(second), Piece::new(first)],
+            1 => vec![Piece::new(second), Piece::reversed(first)],
+            2 => vec![Piece::reversed(second), Piece::new(first)],
+            _ => vec![Piece::reversed(first), Piece::reversed(second)],
+        }
+    }
+
+    fn granular_moves(route: &Route, candidate_list: &CandidateList) -> Vec<ThreeOptMove> {
+        let last_index = route.stops.len().saturating_sub(1);
+        let mut moves = Vec::new();
+
+        // Each reconnection is generated from the new edge leaving `first_start - 1`.
+        for (from, to) in candidate_list.pairs(&route.stops) {
+            let first_start = from + 1;
+
+            if to > first_start && to < last_index {
+                for second_end in to + 1..=last_index {
+                    moves.push((first_start, to, second_end, 0));
+                    moves.push((first_start, to, second_end, 1));
+                }
+            }
+
+            if to < last_index {
+                for second_start in first_start + 1..to + 1 {
+                    moves.push((first_start, second_start, to + 1, 2));
+                }
+
+                for second_end in to + 2..=last_index {
+                    moves.push((first_start, to + 1, second_end, 3));
+                }
+            }
+        }
+
+        moves
+    }
+
+    fn all_moves(route: &Route) -> Vec<ThreeOptMove> {
+        let last_index = route.stops.len().saturating_sub(1);
+        let mut moves = Vec::new();
+
+        for first_start in 1..last_index {
+            for second_start in first_start + 1..last_index {
+                for second_end in second_start + 1..=last_index {
+                    for kind in 0..RECONNECTIONS {
+                        moves.push((first_start, second_start, second_end, kind));
+                    }
+                }
+            }
+        }
+
+        moves
+    }
+}
+
+impl IntraRouteOperator for ThreeOptSearcher {
+    fn distance_service(&self) -> &DistanceService {
+        &self.distance_service
+    }
+
+    fn strategy(&self) -> ImprovementStrategy {
+        self.strategy
+    }
+
+    fn candidate_list(&self) -> Option<&CandidateList> {
+        self.candidate_list.as_ref()
+    }
+
+    fn neighbourhood(&self, route: &Route, profile: &RouteProfile) -> Vec<IntraRouteMove> {
+        let moves = match self.candidate_list() {
+            Some(candidate_list) => Self::granular_moves(route, candidate_list),
+            None => Self::all_moves(route),
+        };
+
+        moves
+            .into_iter()
+            .filter_map(|(first_start, second_start, second_end, kind)| {
+                IntraRouteMove::evaluate(
+                    route,
+                    profile,
+                    first_start..second_end,
+                    Self::reconnection(first_start..second_start, second_start..second_end, kind),
+                    &self.distance_service,
+                )
+            })
+            .collect()
+    }
+}
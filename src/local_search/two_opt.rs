@@ -1,59 +1,99 @@
+use std::collections::BTreeSet;
+
 use crate::{
     domain::{route::Route, stop::Stop},
-    services::distance::distance_service::DistanceMatrix,
-    stop_swapper::{path::Path, StopSwapper},
+    services::distance::{distance_oracle::IntoDistanceOracle, distance_service::DistanceService},
+};
+
+use super::{
+    candidate_list::{CandidateList, Granularity},
+    improvement_strategy::ImprovementStrategy,
+    intra_route::{
+        intra_route_move::{IntraRouteMove, Piece},
+        intra_route_operator::IntraRouteOperator,
+    },
+    route_profile::RouteProfile,
 };
 
 pub struct TwoOptSearcher {
-    stop_swapper: StopSwapper,
+    distance_service: DistanceService,
+    strategy: ImprovementStrategy,
+    candidate_list: Option<CandidateList>,
 }
 
 impl TwoOptSearcher {
-    pub fn new(stops: Vec<Stop>, distances: &DistanceMatrix) -> Self {
+    pub fn new(
+        stops: Vec<Stop>,
+        distances: impl IntoDistanceOracle,
+        strategy: ImprovementStrategy,
+        granularity: Option<Granularity>,
+    ) -> Self {
+        let distance_service = DistanceService::new(stops.clone(), distances);
+        let candidate_list =
+            CandidateList::from_granularity(&stops, &distance_service, granularity);
+
         Self {
-            stop_swapper: StopSwapper::new(stops, distances),
+            strategy,
+            candidate_list,
+            distance_service,
         }
     }
 
-    fn should_swap_stops(swap_cost: f64) -> bool {
-        swap_cost < 0.0
+    pub fn run(&self, route: &mut Route) -> Option<()> {
+        self.run_to_convergence(route)
     }
 
-    fn find_improvements(&self, stops: &Vec<Stop>, path: &Path) -> Option<(usize, f64)> {
-        let (swap_candidate_index, swap_cost) =
-            self.stop_swapper.get_minimum_swap_cost(path, stops)?;
-
-        let swap_candidate = Path::from_stop_index(
-            stops,
-            swap_candidate_index,
-            &self.stop_swapper.distance_service,
-        )?;
+    fn granular_ranges(route: &Route, candidate_list: &CandidateList) -> BTreeSet<(usize, usize)> {
+        let last_index = route.stops.len().saturating_sub(1);
+        let mut ranges = BTreeSet::new();
 
-        if Self::should_swap_stops(swap_cost) {
-            return Some((swap_candidate.current.index, swap_cost));
+        // Reversing `start..end` creates the edges `(start - 1, end - 1)` and `(start, end)`.
+        for (first, second) in candidate_list.pairs(&route.stops) {
+            for (start, end) in [(first + 1, second + 1), (first, second)] {
+                if start >= 1 && start + 2 <= end && end <= last_index {
+                    ranges.insert((start, end));
+                }
+            }
         }
 
-        None
+        ranges
     }
+}
 
-    pub fn run(&self, route: &mut Route) -> Option<()> {
-        for stop_index in 1..route.stops.len() - 1 {
-            let path = Path::from_stop_index(
-                &route.stops,
-                stop_index,
-                &self.stop_swapper.distance_service,
-            )?;
-
-            let (swap_candidate_index, swap_cost) =
-                match self.find_improvements(&route.stops, &path) {
-                    Some(candidate_index) => candidate_index,
-                    None => continue,
-                };
-
-            let base_index = path.current.index;
-            route.swap_stops(base_index, swap_candidate_index, swap_cost);
-        }
+impl IntraRouteOperator for TwoOptSearcher {
+    fn distance_service(&self) -> &DistanceService {
+        &self.distance_service
+    }
+
+    fn strategy(&self) -> ImprovementStrategy {
+        self.strategy
+    }
+
+    fn candidate_list(&self) -> Option<&CandidateList> {
+        self.candidate_list.as_ref()
+    }
+
+    fn neighbourhood(&self, route: &Route, profile: &RouteProfile) -> Vec<IntraRouteMove> {
+        let last_index = route.stops.len().saturating_sub(1);
+
+        let ranges = match self.candidate_list() {
+            Some(candidate_list) => Self::granular_ranges(route, candidate_list),
+            None => (1..last_index)
+                .flat_map(|start| (start + 2..=last_index).map(move |end| (start, end)))
+                .collect(),
+        };
 
-        Some(())
+        ranges
+            .into_iter()
+            .filter_map(|(start, end)| {
+                IntraRouteMove::evaluate(
+                    route,
+                    profile,
+                    start..end,
+                    vec![Piece::reversed(start..end)],
+                    &self.distance_service,
+                )
+            })
+            .collect()
     }
 }
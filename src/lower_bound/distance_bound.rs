@@ -0,0 +1,110 @@
+use crate::{domain::stop::Stop, services::distance::distance_service::DistanceService};
+
+pub type DistanceTable = Vec<Vec<f64>>;
+
+pub fn distance_table(nodes: &[Stop], distance_service: &DistanceService) -> DistanceTable {
+    nodes
+        .iter()
+        .map(|from| {
+            nodes
+                .iter()
+                .map(|to| {
+                    distance_service
+                        .get_distance(from, to)
+                        .unwrap_or(f64::INFINITY)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn edge_weight(distances: &DistanceTable, from: usize, to: usize) -> f64 {
+    distances[from][to].min(distances[to][from])
+}
+
+fn smallest_sum(mut values: Vec<f64>, count: usize) -> f64 {
+    values.sort_by(|value1, value2| value1.partial_cmp(value2).unwrap());
+    values.iter().take(count).sum()
+}
+
+fn forest_edges(distances: &DistanceTable) -> Vec<f64> {
+    let mut edges: Vec<(f64, usize, usize)> = (1..distances.len())
+        .flat_map(|from| (from + 1..distances.len()).map(move |to| (from, to)))
+        .map(|(from, to)| (edge_weight(distances, from, to), from, to))
+        .filter(|(weight, _, _)| weight.is_finite())
+        .collect();
+
+    edges.sort_by(|edge1, edge2| edge1.0.partial_cmp(&edge2.0).unwrap());
+
+    let mut components: Vec<usize> = (0..distances.len()).collect();
+    let mut accepted = Vec::new();
+
+    fn find(components: &mut [usize], node: usize) -> usize {
+        let mut root = node;
+
+        while components[root] != root {
+            root = components[root];
+        }
+
+        components[node] = root;
+        root
+    }
+
+    for (weight, from, to) in edges {
+        let from_root = find(&mut components, from);
+        let to_root = find(&mut components, to);
+
+        if from_root != to_root {
+            components[from_root] = to_root;
+            accepted.push(weight);
+        }
+    }
+
+    accepted
+}
+
+pub fn assignment_bound(distances: &DistanceTable, routes: usize) -> f64 {
+    let customers = 1..distances.len();
+
+    let cheapest = |arc: &dyn Fn(usize, usize) -> f64| -> f64 {
+        let customer_arcs: f64 = customers
+            .clone()
+            .map(|customer| {
+                (0..distances.len())
+                    .filter(|other| *other != customer)
+                    .map(|other| arc(other, customer))
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .sum();
+
+        let depot_arcs: Vec<f64> = customers.clone().map(|customer| arc(customer, 0)).collect();
+
+        customer_arcs + smallest_sum(depot_arcs, routes)
+    };
+
+    let incoming = cheapest(&|from, to| distances[from][to]);
+    let outgoing = cheapest(&|from, to| distances[to][from]);
+
+    incoming.max(outgoing)
+}
+
+pub fn spanning_tree_bound(distances: &DistanceTable, min_routes: usize, max_routes: usize) -> f64 {
+    let customers = distances.len().saturating_sub(1);
+
+    if customers == 0 {
+        return 0.0;
+    }
+
+    let forest = forest_edges(distances);
+    let depot_edges: Vec<f64> = (1..distances.len())
+        .flat_map(|customer| [edge_weight(distances, 0, customer); 2])
+        .collect();
+
+    (min_routes.max(1)..=max_routes.min(customers))
+        .filter(|routes| customers - routes <= forest.len())
+        .map(|routes| {
+            forest.iter().take(customers - routes).sum::<f64>()
+                + smallest_sum(depot_edges.clone(), 2 * routes)
+        })
+        .fold(f64::INFINITY, f64::min)
+}
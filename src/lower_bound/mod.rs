@@ -0,0 +1,5 @@
+pub mod distance_bound;
+mod solution_bound;
+mod tests;
+pub mod vehicle_bound;
+pub use solution_bound::LowerBound;
@@ -0,0 +1,50 @@
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    services::distance::{distance_oracle::IntoDistanceOracle, distance_service::DistanceService},
+};
+
+use super::{
+    distance_bound::{assignment_bound, distance_table, spanning_tree_bound},
+    vehicle_bound::{is_serviceable, minimum_vehicles},
+};
+
+const DEPOT_ID: u32 = 0;
+
+pub struct LowerBound {
+    pub vehicles: usize,
+    pub assignment: f64,
+    pub spanning_tree: f64,
+}
+
+impl LowerBound {
+    pub fn new(
+        vehicles: &[Vehicle],
+        distances: impl IntoDistanceOracle,
+        stops: Vec<Stop>,
+    ) -> Option<Self> {
+        let depot = stops.iter().find(|stop| stop.id == DEPOT_ID).copied()?;
+        let distance_service = DistanceService::new(stops.clone(), distances);
+
+        let customers: Vec<Stop> = stops
+            .iter()
+            .filter(|stop| stop.id != DEPOT_ID)
+            .filter(|stop| is_serviceable(stop, Some(&depot), vehicles))
+            .copied()
+            .collect();
+
+        let minimum_vehicles = minimum_vehicles(&customers, Some(&depot), vehicles)?;
+
+        let nodes: Vec<Stop> = std::iter::once(depot).chain(customers).collect();
+        let table = distance_table(&nodes, &distance_service);
+
+        Some(Self {
+            vehicles: minimum_vehicles,
+            assignment: assignment_bound(&table, minimum_vehicles),
+            spanning_tree: spanning_tree_bound(&table, minimum_vehicles, vehicles.len()),
+        })
+    }
+
+    pub fn distance(&self) -> f64 {
+        self.assignment.max(self.spanning_tree)
+    }
+}
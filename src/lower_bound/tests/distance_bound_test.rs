@@ -0,0 +1,39 @@
+use rstest::rstest;
+
+use crate::domain::stop::Stop;
+use crate::lower_bound::distance_bound::{
+    assignment_bound, distance_table, spanning_tree_bound, DistanceTable,
+};
+use crate::services::distance::distance_service::DistanceService;
+
+use crate::tests::fixtures::services_fixture::distance_service;
+use crate::tests::fixtures::stops_fixture::stops;
+
+fn table(stops: Vec<Stop>, distance_service: DistanceService) -> DistanceTable {
+    distance_table(&stops[..4], &distance_service)
+}
+
+#[rstest]
+fn distance_table_is_built(stops: Vec<Stop>, distance_service: DistanceService) {
+    let table = table(stops, distance_service);
+
+    assert_eq!(table.len(), 4);
+    assert_eq!(table[0], vec![0.0, 2.0, 1.0, 3.0]);
+    assert_eq!(table[3][2], 2.0);
+}
+
+#[rstest]
+fn assignment_bound_is_correct(stops: Vec<Stop>, distance_service: DistanceService) {
+    let table = table(stops, distance_service);
+
+    assert_eq!(assignment_bound(&table, 1), 6.0);
+    assert_eq!(assignment_bound(&table, 2), 8.0);
+}
+
+#[rstest]
+fn spanning_tree_bound_is_correct(stops: Vec<Stop>, distance_service: DistanceService) {
+    let table = table(stops, distance_service);
+
+    assert_eq!(spanning_tree_bound(&table, 1, 2), 7.0);
+    assert_eq!(spanning_tree_bound(&table, 2, 2), 8.0);
+}
@@ -0,0 +1,8 @@
+#[cfg(test)]
+pub mod distance_bound_test;
+
+#[cfg(test)]
+pub mod solution_bound_test;
+
+#[cfg(test)]
+pub mod vehicle_bound_test;
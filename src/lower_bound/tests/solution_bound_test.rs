@@ -0,0 +1,60 @@
+use rstest::rstest;
+
+use crate::domain::stop::Stop;
+use crate::lower_bound::LowerBound;
+use crate::services::distance::distance_service::DistanceMatrix;
+use crate::solvers::branch_and_bound::branch_and_bound_solver::BranchAndBoundSolver;
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+#[rstest]
+fn lower_bound_is_computed(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let lower_bound = LowerBound::new(&vehicle_factory(2), &distances, stops).unwrap();
+
+    assert_eq!(lower_bound.vehicles, 1);
+    assert_eq!(lower_bound.assignment, 6.0);
+    assert_eq!(lower_bound.spanning_tree, 7.0);
+    assert_eq!(lower_bound.distance(), 7.0);
+}
+
+#[rstest]
+fn lower_bound_does_not_exceed_optimal(distances: DistanceMatrix, vehicle_factory: VehicleFactory) {
+    let stops: Vec<Stop> = (0..5).map(|id| Stop::new(id, 4 * id.min(1))).collect();
+
+    let lower_bound = LowerBound::new(&vehicle_factory(2), &distances, stops.clone()).unwrap();
+    let mut solver =
+        BranchAndBoundSolver::new(vehicle_factory(2), &distances, stops, 10_000).unwrap();
+
+    solver.solve();
+
+    assert_eq!(lower_bound.vehicles, 2);
+    assert!(lower_bound.distance() <= solver.solution.total_distance);
+}
+
+#[rstest]
+fn lower_bound_for_infeasible_instance(distances: DistanceMatrix, vehicle_factory: VehicleFactory) {
+    let stops: Vec<Stop> = (0..5).map(|id| Stop::new(id, 6 * id.min(1))).collect();
+
+    assert!(LowerBound::new(&vehicle_factory(2), &distances, stops).is_none());
+}
+
+#[rstest]
+fn solution_reports_gap(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let lower_bound = LowerBound::new(&vehicle_factory(2), &distances, stops.clone()).unwrap();
+    let mut solver =
+        BranchAndBoundSolver::new(vehicle_factory(2), &distances, stops, 10_000).unwrap();
+
+    solver.solve();
+
+    assert_eq!(solver.solution.gap(&lower_bound), 1.0 / 7.0);
+}
@@ -0,0 +1,52 @@
+use rstest::rstest;
+
+use crate::domain::stop::Stop;
+use crate::lower_bound::vehicle_bound::{effective_capacity, is_serviceable, minimum_vehicles};
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+#[rstest]
+fn effective_capacity_accounts_for_depot(vehicle_factory: VehicleFactory) {
+    let vehicles = vehicle_factory(1);
+
+    assert_eq!(effective_capacity(&vehicles[0], None), 9);
+    assert_eq!(effective_capacity(&vehicles[0], Some(&Stop::new(0, 2))), 5);
+}
+
+#[rstest]
+fn oversized_stop_is_not_serviceable(vehicle_factory: VehicleFactory) {
+    let vehicles = vehicle_factory(2);
+
+    assert!(is_serviceable(&Stop::new(1, 9), None, &vehicles));
+    assert!(!is_serviceable(&Stop::new(1, 10), None, &vehicles));
+}
+
+#[rstest]
+fn minimum_vehicles_by_demand(vehicle_factory: VehicleFactory) {
+    let customers: Vec<Stop> = (1..5).map(|id| Stop::new(id, 4)).collect();
+
+    assert_eq!(
+        minimum_vehicles(&customers, None, &vehicle_factory(3)),
+        Some(2)
+    );
+}
+
+#[rstest]
+fn minimum_vehicles_by_large_stops(vehicle_factory: VehicleFactory) {
+    let customers: Vec<Stop> = (1..4).map(|id| Stop::new(id, 6)).collect();
+
+    assert_eq!(
+        minimum_vehicles(&customers, None, &vehicle_factory(3)),
+        Some(3)
+    );
+}
+
+#[rstest]
+fn minimum_vehicles_for_infeasible_instance(vehicle_factory: VehicleFactory) {
+    let customers: Vec<Stop> = (1..5).map(|id| Stop::new(id, 8)).collect();
+
+    assert_eq!(
+        minimum_vehicles(&customers, None, &vehicle_factory(2)),
+        None
+    );
+    assert_eq!(minimum_vehicles(&[], None, &vehicle_factory(2)), Some(0));
+}
@@ -0,0 +1,53 @@
+use crate::domain::{stop::Stop, vehicle::Vehicle};
+
+pub fn effective_capacity(vehicle: &Vehicle, depot: Option<&Stop>) -> u32 {
+    let depot_usage = depot.map_or(0, |depot| depot.usage);
+
+    vehicle
+        .capacity
+        .saturating_sub(depot_usage.saturating_mul(2).saturating_add(1))
+}
+
+pub fn is_serviceable(stop: &Stop, depot: Option<&Stop>, vehicles: &[Vehicle]) -> bool {
+    vehicles
+        .iter()
+        .any(|vehicle| stop.usage <= effective_capacity(vehicle, depot))
+}
+
+pub fn minimum_vehicles(
+    customers: &[Stop],
+    depot: Option<&Stop>,
+    vehicles: &[Vehicle],
+) -> Option<usize> {
+    if customers.is_empty() {
+        return Some(0);
+    }
+
+    let mut capacities: Vec<u32> = vehicles
+        .iter()
+        .map(|vehicle| effective_capacity(vehicle, depot))
+        .collect();
+
+    capacities.sort_by(|capacity1, capacity2| capacity2.cmp(capacity1));
+
+    let demand: u64 = customers.iter().map(|stop| stop.usage as u64).sum();
+    let mut supported = 0;
+
+    let by_demand = capacities.iter().position(|capacity| {
+        supported += *capacity as u64;
+        supported >= demand
+    })? + 1;
+
+    let largest_capacity = capacities.first().copied().unwrap_or_default();
+    let by_large_stops = customers
+        .iter()
+        .filter(|stop| 2 * stop.usage as u64 > largest_capacity as u64)
+        .count();
+
+    let minimum = by_demand.max(by_large_stops);
+
+    match minimum <= vehicles.len() {
+        true => Some(minimum),
+        false => None,
+    }
+}
@@ -0,0 +1,120 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, VecDeque},
+    hash::{Hash, Hasher},
+    sync::RwLock,
+};
+
+use crate::domain::coordinate::Coordinate;
+
+use super::{distance_matrix_builder::DistanceMetric, distance_oracle::DistanceOracle};
+
+const DEFAULT_CACHE_CAPACITY: usize = 1_000_000;
+const CACHE_SHARDS: usize = 16;
+
+type CacheKey = (u32, u32);
+
+#[derive(Default)]
+struct CacheShard {
+    distances: HashMap<CacheKey, f64>,
+    insertion_order: VecDeque<CacheKey>,
+}
+
+impl CacheShard {
+    fn insert(&mut self, key: CacheKey, distance: f64, capacity: usize) {
+        if capacity == 0 || self.distances.contains_key(&key) {
+            return;
+        }
+
+        while self.distances.len() >= capacity {
+            match self.insertion_order.pop_front() {
+                Some(oldest) => self.distances.remove(&oldest),
+                None => break,
+            };
+        }
+
+        self.distances.insert(key, distance);
+        self.insertion_order.push_back(key);
+    }
+}
+
+pub struct CoordinateDistanceOracle {
+    coordinates: HashMap<u32, Coordinate>,
+    metric: DistanceMetric,
+    shards: Vec<RwLock<CacheShard>>,
+    shard_capacity: usize,
+}
+
+impl CoordinateDistanceOracle {
+    pub fn new(coordinates: Vec<Coordinate>, metric: DistanceMetric) -> Self {
+        Self {
+            coordinates: coordinates
+                .into_iter()
+                .map(|coordinate| (coordinate.id, coordinate))
+                .collect(),
+            metric,
+            shards: Vec::new(),
+            shard_capacity: 0,
+        }
+        .with_cache_capacity(DEFAULT_CACHE_CAPACITY)
+    }
+
+    /// Caps the number of cached distances; the oldest entries are evicted first.
+    /// Only haversine distances are cached, the other metrics are cheaper to recompute.
+    pub fn with_cache_capacity(mut self, cache_capacity: usize) -> Self {
+        let shards = cache_capacity.clamp(1, CACHE_SHARDS);
+
+        self.shards = (0..shards).map(|_| Default::default()).collect();
+        self.shard_capacity = cache_capacity / shards;
+        self
+    }
+
+    pub fn cached_distances(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.read().unwrap().distances.len())
+            .sum()
+    }
+
+    fn is_cached(&self) -> bool {
+        self.metric == DistanceMetric::Haversine && self.shard_capacity > 0
+    }
+
+    fn shard(&self, key: &CacheKey) -> &RwLock<CacheShard> {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+
+        &self.shards[hasher.finish() as usize % self.shards.len()]
+    }
+
+    fn compute(&self, from: u32, to: u32) -> Option<f64> {
+        Some(
+            self.metric
+                .distance(self.coordinates.get(&from)?, self.coordinates.get(&to)?),
+        )
+    }
+}
+
+impl DistanceOracle for CoordinateDistanceOracle {
+    fn distance(&self, from: u32, to: u32) -> Option<f64> {
+        if !self.is_cached() {
+            return self.compute(from, to);
+        }
+
+        // Every metric is symmetric, so both directions share a cache entry.
+        let key = (from.min(to), from.max(to));
+        let shard = self.shard(&key);
+
+        if let Some(distance) = shard.read().unwrap().distances.get(&key) {
+            return Some(*distance);
+        }
+
+        let distance = self.compute(from, to)?;
+
+        shard
+            .write()
+            .unwrap()
+            .insert(key, distance, self.shard_capacity);
+
+        Some(distance)
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use super::{
+    distance_oracle::{sort_neighbours, DistanceOracle},
+    distance_service::DistanceMatrix,
+};
+
+pub struct DenseDistanceMatrix {
+    ids: Vec<u32>,
+    indexes: HashMap<u32, usize>,
+    distances: Vec<f64>,
+}
+
+impl DenseDistanceMatrix {
+    pub fn new(ids: Vec<u32>, distances: &DistanceMatrix) -> Self {
+        Self::from_fn(ids, |from, to| distances.get(&(from, to)).copied())
+    }
+
+    pub fn from_fn(ids: Vec<u32>, distance: impl Fn(u32, u32) -> Option<f64>) -> Self {
+        let indexes = ids
+            .iter()
+            .enumerate()
+            .map(|(index, &id)| (id, index))
+            .collect();
+
+        let distances = ids
+            .iter()
+            .flat_map(|&from| ids.iter().map(move |&to| (from, to)))
+            .map(|(from, to)| distance(from, to).unwrap_or(f64::NAN))
+            .collect();
+
+        Self {
+            ids,
+            indexes,
+            distances,
+        }
+    }
+
+    fn row(&self, stop: u32) -> Option<&[f64]> {
+        let index = *self.indexes.get(&stop)?;
+        let size = self.ids.len();
+
+        Some(&self.distances[index * size..(index + 1) * size])
+    }
+}
+
+impl DistanceOracle for DenseDistanceMatrix {
+    fn distance(&self, from: u32, to: u32) -> Option<f64> {
+        let distance = self.row(from)?[*self.indexes.get(&to)?];
+
+        (!distance.is_nan()).then_some(distance)
+    }
+
+    fn neighbours(&self, stop: u32, k: usize) -> Option<Vec<u32>> {
+        let mut neighbours = self
+            .row(stop)
+            .map(|row| {
+                self.ids
+                    .iter()
+                    .zip(row)
+                    .filter(|(&to, distance)| to != stop && !distance.is_nan())
+                    .map(|(&to, &distance)| (to, distance))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        sort_neighbours(&mut neighbours);
+
+        Some(neighbours.into_iter().take(k).map(|(to, _)| to).collect())
+    }
+}
@@ -2,13 +2,13 @@ use std::cmp::Ordering;
 
 use crate::domain::stop::Stop;
 
-pub(super) struct DistanceMatrixEntry {
+pub(super) struct DistanceMatrixEntry<'a> {
     pub(super) distance: f64,
-    pub(super) destination: Stop,
+    pub(super) destination: &'a Stop,
 }
 
-impl DistanceMatrixEntry {
-    pub(super) fn new(destination: Stop, distance: f64) -> DistanceMatrixEntry {
+impl<'a> DistanceMatrixEntry<'a> {
+    pub(super) fn new(destination: &'a Stop, distance: f64) -> DistanceMatrixEntry<'a> {
         DistanceMatrixEntry {
             distance,
             destination,
@@ -16,16 +16,16 @@ impl DistanceMatrixEntry {
     }
 }
 
-impl PartialOrd for DistanceMatrixEntry {
+impl PartialOrd for DistanceMatrixEntry<'_> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.distance.partial_cmp(&other.distance)
     }
 }
 
-impl PartialEq for DistanceMatrixEntry {
+impl PartialEq for DistanceMatrixEntry<'_> {
     fn eq(&self, other: &Self) -> bool {
         self.distance == other.distance
     }
 }
 
-impl Eq for DistanceMatrixEntry {}
+impl Eq for DistanceMatrixEntry<'_> {}
@@ -0,0 +1,84 @@
+use crate::domain::coordinate::Coordinate;
+
+use super::distance_service::DistanceMatrix;
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+#[derive(Copy, Clone, Default, PartialEq, Debug)]
+pub enum DistanceMetric {
+    #[default]
+    Euclidean,
+    /// Euclidean distance rounded to the nearest integer, as TSPLIB `EUC_2D` (`nint`).
+    RoundedEuclidean,
+    Manhattan,
+    /// Great-circle distance in kilometres; `x` is the longitude and `y` the latitude, in degrees.
+    Haversine,
+}
+
+impl DistanceMetric {
+    pub fn from_code(code: u32) -> Option<DistanceMetric> {
+        match code {
+            0 => Some(DistanceMetric::Euclidean),
+            1 => Some(DistanceMetric::RoundedEuclidean),
+            2 => Some(DistanceMetric::Manhattan),
+            3 => Some(DistanceMetric::Haversine),
+            _ => None,
+        }
+    }
+
+    pub fn distance(&self, from: &Coordinate, to: &Coordinate) -> f64 {
+        let dx = from.x - to.x;
+        let dy = from.y - to.y;
+
+        match self {
+            DistanceMetric::Euclidean => dx.hypot(dy),
+            DistanceMetric::RoundedEuclidean => (dx.hypot(dy) + 0.5).floor(),
+            DistanceMetric::Manhattan => dx.abs() + dy.abs(),
+            DistanceMetric::Haversine => {
+                let (from_lat, to_lat) = (from.y.to_radians(), to.y.to_radians());
+                let half_dlat = (to_lat - from_lat) / 2.0;
+                let half_dlon = (to.x - from.x).to_radians() / 2.0;
+
+                let a = half_dlat.sin().powi(2)
+                    + from_lat.cos() * to_lat.cos() * half_dlon.sin().powi(2);
+
+                2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+            }
+        }
+    }
+}
+
+pub struct DistanceMatrixBuilder {
+    coordinates: Vec<Coordinate>,
+    metric: DistanceMetric,
+}
+
+impl DistanceMatrixBuilder {
+    pub fn new(coordinates: Vec<Coordinate>) -> DistanceMatrixBuilder {
+        DistanceMatrixBuilder {
+            coordinates,
+            metric: DistanceMetric::default(),
+        }
+    }
+
+    pub fn with_metric(mut self, metric: DistanceMetric) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    pub fn build(&self) -> DistanceMatrix {
+        let mut distances = DistanceMatrix::new();
+
+        for from in &self.coordinates {
+            for to in &self.coordinates {
+                if from.id == to.id {
+                    continue;
+                }
+
+                distances.insert((from.id, to.id), self.metric.distance(from, to));
+            }
+        }
+
+        distances
+    }
+}
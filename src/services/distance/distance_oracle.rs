@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use super::{distance_service::DistanceMatrix, sparse_distance_matrix::SparseDistanceMatrix};
+
+pub trait DistanceOracle: Send + Sync {
+    fn distance(&self, from: u32, to: u32) -> Option<f64>;
+
+    /// Returns up to `k` stops reachable from `stop`, sorted by distance and then by id,
+    /// or `None` when the oracle can't answer neighbour queries cheaply.
+    fn neighbours(&self, _stop: u32, _k: usize) -> Option<Vec<u32>> {
+        None
+    }
+}
+
+pub type SharedDistanceOracle = Arc<dyn DistanceOracle>;
+
+pub trait IntoDistanceOracle: Copy {
+    fn into_oracle(self) -> SharedDistanceOracle;
+}
+
+/// Copies the matrix on every call; convert once and pass the shared oracle on when several
+/// components need the same distances.
+impl IntoDistanceOracle for &DistanceMatrix {
+    fn into_oracle(self) -> SharedDistanceOracle {
+        Arc::new(SparseDistanceMatrix::new(self))
+    }
+}
+
+impl IntoDistanceOracle for &SharedDistanceOracle {
+    fn into_oracle(self) -> SharedDistanceOracle {
+        self.clone()
+    }
+}
+
+pub(super) fn sort_neighbours(neighbours: &mut [(u32, f64)]) {
+    neighbours.sort_by(|(stop1, distance1), (stop2, distance2)| {
+        distance1
+            .partial_cmp(distance2)
+            .unwrap()
+            .then_with(|| stop1.cmp(stop2))
+    });
+}
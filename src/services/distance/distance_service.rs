@@ -2,82 +2,157 @@ use std::{cmp::min, collections::HashMap};
 
 use crate::domain::stop::Stop;
 
-use super::distance_matrix::DistanceMatrixEntry;
+use super::{
+    distance_matrix::DistanceMatrixEntry,
+    distance_oracle::{IntoDistanceOracle, SharedDistanceOracle},
+};
 
 pub type DistancesMatrixKey = (u32, u32);
 pub type DistanceMatrix = HashMap<DistancesMatrixKey, f64>;
-pub(super) type MappedDistanceMatrix = HashMap<DistancesMatrixKey, DistanceMatrixEntry>;
 
 pub type StopsMap = HashMap<u32, Stop>;
 
 pub struct DistanceService {
-    distances: MappedDistanceMatrix,
+    oracle: SharedDistanceOracle,
+    stops: Vec<Stop>,
+    stops_map: StopsMap,
 }
 
 impl<'a> DistanceService {
-    pub fn new(stops: Vec<Stop>, distances: &DistanceMatrix) -> DistanceService {
+    pub fn new(stops: Vec<Stop>, distances: impl IntoDistanceOracle) -> DistanceService {
+        let stops_map = stops.iter().map(|stop| (stop.id, *stop)).collect();
+
         DistanceService {
-            distances: Self::map_distances(stops, distances),
+            oracle: distances.into_oracle(),
+            stops,
+            stops_map,
         }
     }
 
-    fn map_distances(stops: Vec<Stop>, distances: &DistanceMatrix) -> MappedDistanceMatrix {
-        let stops_map: StopsMap = stops.iter().map(|stop| (stop.id, *stop)).collect();
-
-        distances
-            .iter()
-            .map(|x| {
-                (
-                    (x.0 .0, x.0 .1),
-                    DistanceMatrixEntry::new(*stops_map.get(&x.0 .1).unwrap(), *x.1),
-                )
-            })
-            .collect()
+    pub fn oracle(&self) -> &SharedDistanceOracle {
+        &self.oracle
     }
 
     pub fn get_distance(&self, from: &Stop, to: &Stop) -> Option<f64> {
-        Some(self.distances.get(&(from.id, to.id))?.distance)
+        match self.oracle.distance(from.id, to.id) {
+            Some(distance) => Some(distance),
+            None if from == to => Some(0.0),
+            None => None,
+        }
     }
 
     pub(super) fn get_distances_from(
         &'a self,
         stop: &'a Stop,
-    ) -> impl Iterator<Item = &DistanceMatrixEntry> {
-        self.distances
+    ) -> impl Iterator<Item = DistanceMatrixEntry<'a>> {
+        self.stops
             .iter()
-            .filter(|x| x.0 .0 == stop.id)
-            .map(|x| x.1)
+            .filter(move |destination| destination.id != stop.id)
+            .filter_map(move |destination| {
+                self.oracle
+                    .distance(stop.id, destination.id)
+                    .map(|distance| DistanceMatrixEntry::new(destination, distance))
+            })
     }
 
     pub fn get_nearest_stop(
         &'a self,
         stop: &'a Stop,
         filter: impl Fn(&Stop) -> bool,
-    ) -> Option<&Stop> {
+    ) -> Option<&'a Stop> {
         self.get_distances_from(stop)
-            .filter(|entry| filter(&entry.destination))
+            .filter(|entry| filter(entry.destination))
             .min_by(|stop1, stop2| stop1.partial_cmp(stop2).unwrap())
-            .map(|x| &x.destination)
+            .map(|x| x.destination)
     }
 
-    pub fn get_k_nearest_stops(
+    fn get_sorted_distances_from(
         &'a self,
         stop: &'a Stop,
-        k: usize,
         filter: impl Fn(&Stop) -> bool,
-    ) -> Vec<&Stop> {
+    ) -> Vec<DistanceMatrixEntry<'a>> {
         let mut stops = self
             .get_distances_from(stop)
-            .filter(|entry| filter(&entry.destination))
-            .collect::<Vec<&DistanceMatrixEntry>>();
+            .filter(|entry| filter(entry.destination))
+            .collect::<Vec<DistanceMatrixEntry>>();
 
-        stops.sort_by(|stop1, stop2| stop1.partial_cmp(stop2).unwrap());
+        stops.sort_by(|stop1, stop2| {
+            stop1
+                .partial_cmp(stop2)
+                .unwrap()
+                .then_with(|| stop1.destination.id.cmp(&stop2.destination.id))
+        });
 
+        stops
+    }
+
+    pub fn get_k_nearest_stops(
+        &'a self,
+        stop: &'a Stop,
+        k: usize,
+        filter: impl Fn(&Stop) -> bool,
+    ) -> Vec<&'a Stop> {
+        if let Some(neighbours) = self.get_neighbours(stop, k, &filter) {
+            return neighbours;
+        }
+
+        let stops = self.get_sorted_distances_from(stop, filter);
         let number_of_stops = min(stops.len(), k);
 
         stops[0..number_of_stops]
             .iter()
-            .map(|x| &x.destination)
+            .map(|x| x.destination)
             .collect()
     }
+
+    pub fn get_stops_within_alpha(
+        &'a self,
+        stop: &'a Stop,
+        alpha: f64,
+        filter: impl Fn(&Stop) -> bool,
+    ) -> Vec<&'a Stop> {
+        let stops = self.get_sorted_distances_from(stop, filter);
+
+        let (Some(nearest), Some(farthest)) = (stops.first(), stops.last()) else {
+            return Vec::new();
+        };
+
+        let threshold =
+            nearest.distance + alpha.clamp(0.0, 1.0) * (farthest.distance - nearest.distance);
+
+        stops
+            .iter()
+            .take_while(|x| x.distance <= threshold)
+            .map(|x| x.destination)
+            .collect()
+    }
+
+    fn get_neighbours(
+        &'a self,
+        stop: &Stop,
+        k: usize,
+        filter: &impl Fn(&Stop) -> bool,
+    ) -> Option<Vec<&'a Stop>> {
+        let mut requested = k;
+
+        // Filtered or unknown neighbours leave gaps, so widen the request until `k` survive
+        // or the oracle runs out of neighbours.
+        loop {
+            let candidates = self.oracle.neighbours(stop.id, requested)?;
+            let is_exhausted = candidates.len() < requested;
+
+            let neighbours = candidates
+                .iter()
+                .filter_map(|id| self.stops_map.get(id))
+                .filter(|stop| filter(stop))
+                .take(k)
+                .collect::<Vec<&Stop>>();
+
+            if neighbours.len() == k || is_exhausted {
+                return Some(neighbours);
+            }
+
+            requested = requested.saturating_mul(2);
+        }
+    }
 }
@@ -1,3 +1,8 @@
+pub mod coordinate_distance_oracle;
+pub mod dense_distance_matrix;
 pub mod distance_matrix;
+pub mod distance_matrix_builder;
+pub mod distance_oracle;
 pub mod distance_service;
+pub mod sparse_distance_matrix;
 mod tests;
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use super::{
+    distance_oracle::{sort_neighbours, DistanceOracle},
+    distance_service::DistanceMatrix,
+};
+
+pub struct SparseDistanceMatrix {
+    rows: HashMap<u32, HashMap<u32, f64>>,
+}
+
+impl SparseDistanceMatrix {
+    pub fn new(distances: &DistanceMatrix) -> Self {
+        let mut rows: HashMap<u32, HashMap<u32, f64>> = HashMap::new();
+
+        for (&(from, to), &distance) in distances {
+            rows.entry(from).or_default().insert(to, distance);
+        }
+
+        Self { rows }
+    }
+}
+
+impl DistanceOracle for SparseDistanceMatrix {
+    fn distance(&self, from: u32, to: u32) -> Option<f64> {
+        self.rows.get(&from)?.get(&to).copied()
+    }
+
+    fn neighbours(&self, stop: u32, k: usize) -> Option<Vec<u32>> {
+        let mut neighbours = self
+            .rows
+            .get(&stop)
+            .map(|row| {
+                row.iter()
+                    .filter(|(&to, _)| to != stop)
+                    .map(|(&to, &distance)| (to, distance))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        sort_neighbours(&mut neighbours);
+
+        Some(neighbours.into_iter().take(k).map(|(to, _)| to).collect())
+    }
+}
@@ -0,0 +1,79 @@
+use rstest::rstest;
+
+use crate::{
+    domain::coordinate::Coordinate,
+    services::distance::distance_matrix_builder::{DistanceMatrixBuilder, DistanceMetric},
+};
+
+#[rstest]
+#[case(DistanceMetric::Euclidean, 5.0)]
+#[case(DistanceMetric::RoundedEuclidean, 5.0)]
+#[case(DistanceMetric::Manhattan, 7.0)]
+fn planar_metrics(#[case] metric: DistanceMetric, #[case] expected: f64) {
+    let from = Coordinate::new(0, 0.0, 0.0);
+    let to = Coordinate::new(1, 3.0, 4.0);
+
+    assert_eq!(metric.distance(&from, &to), expected);
+    assert_eq!(metric.distance(&to, &from), expected);
+}
+
+#[rstest]
+#[case(1.4, 1.0)]
+#[case(1.5, 2.0)]
+#[case(2.6, 3.0)]
+fn rounded_euclidean_uses_nint(#[case] x: f64, #[case] expected: f64) {
+    let from = Coordinate::new(0, 0.0, 0.0);
+    let to = Coordinate::new(1, x, 0.0);
+
+    assert_eq!(
+        DistanceMetric::RoundedEuclidean.distance(&from, &to),
+        expected
+    );
+}
+
+#[rstest]
+fn haversine_between_paris_and_london() {
+    let paris = Coordinate::new(0, 2.3522, 48.8566);
+    let london = Coordinate::new(1, -0.1276, 51.5072);
+
+    let distance = DistanceMetric::Haversine.distance(&paris, &london);
+
+    assert!((distance - 343.5).abs() < 1.0);
+}
+
+#[rstest]
+fn haversine_of_same_point_is_zero() {
+    let point = Coordinate::new(0, 10.0, 45.0);
+
+    assert_eq!(DistanceMetric::Haversine.distance(&point, &point), 0.0);
+}
+
+#[rstest]
+#[case(0, Some(DistanceMetric::Euclidean))]
+#[case(1, Some(DistanceMetric::RoundedEuclidean))]
+#[case(2, Some(DistanceMetric::Manhattan))]
+#[case(3, Some(DistanceMetric::Haversine))]
+#[case(4, None)]
+fn metric_from_code(#[case] code: u32, #[case] expected: Option<DistanceMetric>) {
+    assert_eq!(DistanceMetric::from_code(code), expected);
+}
+
+#[rstest]
+fn build_contains_every_ordered_pair() {
+    let coordinates = vec![
+        Coordinate::new(0, 0.0, 0.0),
+        Coordinate::new(1, 3.0, 4.0),
+        Coordinate::new(2, 0.0, 2.0),
+    ];
+
+    let distances = DistanceMatrixBuilder::new(coordinates)
+        .with_metric(DistanceMetric::Manhattan)
+        .build();
+
+    assert_eq!(distances.len(), 6);
+    assert!(!distances.contains_key(&(0, 0)));
+    assert_eq!(distances[&(0, 1)], 7.0);
+    assert_eq!(distances[&(1, 0)], 7.0);
+    assert_eq!(distances[&(1, 2)], 5.0);
+    assert_eq!(distances[&(2, 0)], 2.0);
+}
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::{coordinate::Coordinate, stop::Stop},
+    services::distance::{
+        coordinate_distance_oracle::CoordinateDistanceOracle,
+        dense_distance_matrix::DenseDistanceMatrix,
+        distance_matrix_builder::DistanceMetric,
+        distance_oracle::{DistanceOracle, SharedDistanceOracle},
+        distance_service::{DistanceMatrix, DistanceService},
+        sparse_distance_matrix::SparseDistanceMatrix,
+    },
+    solvers::tabu::tabu_solver::TabuSolver,
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+fn matrix_oracles(distances: &DistanceMatrix) -> Vec<SharedDistanceOracle> {
+    vec![
+        Arc::new(SparseDistanceMatrix::new(distances)),
+        Arc::new(DenseDistanceMatrix::new(vec![0, 1, 2, 3, 4], distances)),
+    ]
+}
+
+#[rstest]
+fn matrix_oracles_return_matrix_distances(distances: DistanceMatrix) {
+    for oracle in matrix_oracles(&distances) {
+        for (&(from, to), &distance) in &distances {
+            assert_eq!(oracle.distance(from, to), Some(distance));
+        }
+
+        assert_eq!(oracle.distance(0, 0), None);
+        assert_eq!(oracle.distance(0, 9), None);
+    }
+}
+
+#[rstest]
+fn matrix_oracles_return_sorted_neighbours(distances: DistanceMatrix) {
+    for oracle in matrix_oracles(&distances) {
+        assert_eq!(oracle.neighbours(0, 3), Some(vec![4, 2, 1]));
+        assert_eq!(oracle.neighbours(3, 10), Some(vec![2, 0, 1, 4]));
+        assert_eq!(oracle.neighbours(9, 2), Some(vec![]));
+    }
+}
+
+fn coordinates() -> Vec<Coordinate> {
+    vec![
+        Coordinate::new(0, 0.0, 0.0),
+        Coordinate::new(1, 3.0, 4.0),
+        Coordinate::new(2, 6.0, 8.0),
+    ]
+}
+
+#[rstest]
+fn coordinate_oracle_computes_cheap_metrics_directly() {
+    let oracle = CoordinateDistanceOracle::new(coordinates(), DistanceMetric::Euclidean);
+
+    assert_eq!(oracle.distance(0, 1), Some(5.0));
+    assert_eq!(oracle.distance(1, 0), Some(5.0));
+    assert_eq!(oracle.distance(0, 2), Some(10.0));
+    assert_eq!(oracle.distance(0, 9), None);
+    assert_eq!(oracle.neighbours(0, 1), None);
+    assert_eq!(oracle.cached_distances(), 0);
+}
+
+#[rstest]
+fn coordinate_oracle_caches_symmetric_haversine_distances() {
+    let oracle = CoordinateDistanceOracle::new(coordinates(), DistanceMetric::Haversine);
+    let expected = DistanceMetric::Haversine.distance(&coordinates()[0], &coordinates()[1]);
+
+    assert_eq!(oracle.cached_distances(), 0);
+    assert_eq!(oracle.distance(0, 1), Some(expected));
+    assert_eq!(oracle.distance(1, 0), Some(expected));
+    assert_eq!(oracle.cached_distances(), 1);
+    assert!(oracle.distance(0, 2).is_some());
+    assert_eq!(oracle.cached_distances(), 2);
+    assert_eq!(oracle.distance(0, 9), None);
+}
+
+#[rstest]
+fn coordinate_oracle_evicts_oldest_distances() {
+    let oracle = CoordinateDistanceOracle::new(coordinates(), DistanceMetric::Haversine)
+        .with_cache_capacity(1);
+
+    let first = oracle.distance(0, 1);
+    let second = oracle.distance(1, 2);
+
+    assert_eq!(oracle.cached_distances(), 1);
+    assert_eq!(oracle.distance(0, 1), first);
+    assert_eq!(oracle.distance(2, 1), second);
+    assert_eq!(oracle.cached_distances(), 1);
+}
+
+#[rstest]
+fn distance_service_uses_oracle(distances: DistanceMatrix, stops: Vec<Stop>) {
+    for oracle in matrix_oracles(&distances) {
+        let distance_service = DistanceService::new(stops.clone(), &oracle);
+
+        assert_eq!(
+            distance_service.get_distance(&stops[0], &stops[3]),
+            Some(3.0)
+        );
+        assert_eq!(
+            distance_service.get_distance(&stops[0], &stops[0]),
+            Some(0.0)
+        );
+
+        let nearest = distance_service.get_k_nearest_stops(&stops[0], 2, |stop| stop.id != 4);
+
+        assert_eq!(
+            nearest.iter().map(|stop| stop.id).collect::<Vec<u32>>(),
+            vec![2, 1]
+        );
+
+        let all = distance_service.get_k_nearest_stops(&stops[0], 10, |stop| stop.id != 4);
+
+        assert_eq!(
+            all.iter().map(|stop| stop.id).collect::<Vec<u32>>(),
+            vec![2, 1, 3]
+        );
+    }
+}
+
+#[rstest]
+fn solver_accepts_shared_oracle(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let oracle: SharedDistanceOracle =
+        Arc::new(DenseDistanceMatrix::new(vec![0, 1, 2, 3, 4], &distances));
+
+    let mut solver = TabuSolver::new(vehicle_factory(2), &oracle, stops, 3, 20, 5, 2, &mut rng);
+    solver.solve();
+
+    assert_eq!(solver.solution.total_distance, 8.0);
+}
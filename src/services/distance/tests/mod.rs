@@ -1,2 +1,8 @@
+#[cfg(test)]
+pub mod distance_matrix_builder_test;
+
+#[cfg(test)]
+pub mod distance_oracle_test;
+
 #[cfg(test)]
 pub mod route_service_test;
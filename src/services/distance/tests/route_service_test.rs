@@ -1,5 +1,9 @@
+use std::collections::HashMap;
+
 use rstest::rstest;
 
+use crate::solvers::solution::Solution;
+
 use crate::tests::fixtures::services_fixture::{route_service_factory, RouteServiceFactory};
 
 #[rstest]
@@ -54,3 +58,88 @@ fn cannot_get_infeasible_near_stops(route_service_factory: RouteServiceFactory)
 
     assert_ne!(stop.id, 4)
 }
+
+#[rstest]
+#[case(0.0, vec![2])]
+#[case(0.5, vec![2, 1])]
+#[case(1.0, vec![2, 1, 3])]
+fn can_get_alpha_nearest_stops(
+    route_service_factory: RouteServiceFactory,
+    #[case] alpha: f64,
+    #[case] expected: Vec<u32>,
+) {
+    let mut route_service = route_service_factory(1);
+
+    route_service.assign_stop_to_route(0, 0).unwrap();
+
+    let alpha_nearest: Vec<u32> = route_service
+        .get_alpha_nearest_stops(0, alpha)
+        .unwrap()
+        .iter()
+        .map(|stop| stop.id)
+        .collect();
+
+    assert_eq!(alpha_nearest, expected);
+}
+
+fn solution(routes: Vec<(u32, Vec<u32>)>) -> Solution {
+    Solution {
+        total_distance: 0.0,
+        result: HashMap::from_iter(routes),
+    }
+}
+
+#[rstest]
+fn can_load_solution(route_service_factory: RouteServiceFactory) {
+    let mut route_service = route_service_factory(2);
+
+    route_service
+        .load_solution(&solution(vec![(0, vec![0, 2, 3, 1, 0]), (1, vec![0, 0])]))
+        .unwrap();
+
+    let stop_ids: Vec<u32> = route_service
+        .get_route(0)
+        .unwrap()
+        .stops
+        .iter()
+        .map(|stop| stop.id)
+        .collect();
+
+    assert_eq!(stop_ids, vec![0, 2, 3, 1, 0]);
+    assert_eq!(route_service.total_distance(), 8.0);
+}
+
+#[rstest]
+fn loaded_solution_is_completed(route_service_factory: RouteServiceFactory) {
+    let mut route_service = route_service_factory(2);
+
+    route_service
+        .load_solution(&solution(vec![(0, vec![0, 2, 0])]))
+        .unwrap();
+
+    let mut stop_ids: Vec<u32> = route_service
+        .get_all_routes()
+        .values()
+        .flat_map(|route| route.stops.iter().map(|stop| stop.id))
+        .filter(|id| *id != 0)
+        .collect();
+    stop_ids.sort();
+
+    assert_eq!(stop_ids, vec![1, 2, 3]);
+}
+
+#[rstest]
+#[case(vec![(2, vec![0, 1, 0])])]
+#[case(vec![(0, vec![0, 1, 1, 0])])]
+#[case(vec![(0, vec![0, 1, 0]), (1, vec![0, 1, 0])])]
+#[case(vec![(0, vec![1, 2, 0])])]
+#[case(vec![(0, vec![0, 9, 0])])]
+#[case(vec![(0, vec![0, 4, 0])])]
+fn cannot_load_invalid_solution(
+    route_service_factory: RouteServiceFactory,
+    #[case] routes: Vec<(u32, Vec<u32>)>,
+) {
+    let mut route_service = route_service_factory(2);
+
+    assert!(route_service.load_solution(&solution(routes)).is_err());
+}
@@ -1,2 +1,3 @@
 pub mod distance;
+pub mod reoptimization;
 pub mod route;
@@ -0,0 +1,24 @@
+use std::collections::HashMap;
+
+use crate::solvers::solution::Solution;
+
+pub struct LivePlan {
+    pub solution: Solution,
+    pub visited: HashMap<u32, usize>,
+}
+
+impl LivePlan {
+    pub fn new(solution: Solution, visited: HashMap<u32, usize>) -> Self {
+        Self { solution, visited }
+    }
+
+    pub fn locked_stops(&self, vehicle_id: u32) -> usize {
+        self.visited.get(&vehicle_id).copied().unwrap_or(0).max(1)
+    }
+}
+
+pub struct ReoptimizationResult {
+    pub solution: Solution,
+    pub changed_vehicles: Vec<u32>,
+    pub unassigned_stops: Vec<u32>,
+}
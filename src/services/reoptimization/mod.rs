@@ -0,0 +1,3 @@
+pub mod live_plan;
+pub mod reoptimization_service;
+mod tests;
@@ -0,0 +1,210 @@
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    errors::solver::invalid_solution::InvalidSolutionError,
+    local_search::{improvement_strategy::ImprovementStrategy, two_opt::TwoOptSearcher},
+    services::{
+        distance::{distance_oracle::IntoDistanceOracle, distance_service::DistanceService},
+        route::route_service::RouteService,
+    },
+    solvers::solution::Solution,
+};
+
+use super::live_plan::{LivePlan, ReoptimizationResult};
+
+struct Insertion {
+    route_index: usize,
+    stop_index: usize,
+    position: usize,
+    cost: f64,
+}
+
+pub struct ReoptimizationService {
+    route_service: RouteService,
+    distance_service: DistanceService,
+    two_opt: TwoOptSearcher,
+}
+
+impl ReoptimizationService {
+    pub fn new(
+        vehicles: Vec<Vehicle>,
+        distances: impl IntoDistanceOracle,
+        stops: Vec<Stop>,
+    ) -> Self {
+        let oracle = distances.into_oracle();
+
+        Self {
+            distance_service: DistanceService::new(stops.clone(), &oracle),
+            two_opt: TwoOptSearcher::new(
+                stops.clone(),
+                &oracle,
+                ImprovementStrategy::BestImprovement,
+                None,
+            ),
+            route_service: RouteService::new(vehicles, &oracle, stops),
+        }
+    }
+
+    fn distance(&self, from: &Stop, to: &Stop) -> Option<f64> {
+        self.distance_service.get_distance(from, to)
+    }
+
+    fn insertion_cost(&self, route: &Route, position: usize, stop: &Stop) -> Option<f64> {
+        let previous = route.stops.get(position - 1)?;
+        let next = route.stops.get(position)?;
+
+        Some(
+            self.distance(previous, stop)? + self.distance(stop, next)?
+                - self.distance(previous, next)?,
+        )
+    }
+
+    fn cheapest_insertion(
+        &self,
+        routes: &[Route],
+        locked: &[usize],
+        stops: &[Stop],
+    ) -> Option<Insertion> {
+        let mut best: Option<Insertion> = None;
+
+        for (stop_index, stop) in stops.iter().enumerate() {
+            for (route_index, route) in routes.iter().enumerate() {
+                if !route.can_add_stop(stop) {
+                    continue;
+                }
+
+                for position in locked[route_index]..route.stops.len() {
+                    let cost = match self.insertion_cost(route, position, stop) {
+                        Some(cost) => cost,
+                        None => continue,
+                    };
+
+                    if best.as_ref().map_or(true, |best| cost < best.cost) {
+                        best = Some(Insertion {
+                            route_index,
+                            stop_index,
+                            position,
+                            cost,
+                        });
+                    }
+                }
+            }
+        }
+
+        best
+    }
+
+    fn build_route(&self, vehicle: &Vehicle, stops: &[Stop]) -> Option<Route> {
+        let mut route = Route::new(Vehicle::new(vehicle.id, vehicle.capacity));
+        let mut previous: Option<&Stop> = None;
+
+        for stop in stops {
+            let distance = match previous {
+                Some(previous) => self.distance(previous, stop)?,
+                None => 0.0,
+            };
+
+            route.add_stop(*stop, distance).ok()?;
+            previous = Some(stop);
+        }
+
+        Some(route)
+    }
+
+    fn reoptimize_tail(&self, route: &Route, locked: usize) -> Option<Route> {
+        let anchor = locked - 1;
+
+        let mut tail = self.build_route(
+            &Vehicle::new(route.vehicle.id, u32::MAX),
+            &route.stops[anchor..],
+        )?;
+        self.two_opt.run(&mut tail);
+
+        let stops: Vec<Stop> = route.stops[..anchor]
+            .iter()
+            .chain(tail.stops.iter())
+            .copied()
+            .collect();
+
+        self.build_route(&route.vehicle, &stops)
+    }
+
+    fn stop_ids(route: &Route) -> Vec<u32> {
+        route.stops.iter().map(|stop| stop.id).collect()
+    }
+
+    pub fn insert_stops(
+        &mut self,
+        plan: &LivePlan,
+        new_stop_ids: &[u32],
+        reoptimize: bool,
+    ) -> Result<ReoptimizationResult, InvalidSolutionError> {
+        self.route_service.load_routes(&plan.solution)?;
+        self.route_service.assign_stop_points();
+
+        let mut new_stops = new_stop_ids
+            .iter()
+            .map(|stop_id| {
+                self.route_service
+                    .get_available_stops()
+                    .get(stop_id)
+                    .copied()
+            })
+            .collect::<Option<Vec<Stop>>>()
+            .ok_or_else(InvalidSolutionError::new)?;
+
+        let mut routes: Vec<Route> = self
+            .route_service
+            .get_all_routes()
+            .values()
+            .cloned()
+            .collect();
+        let original: Vec<Vec<u32>> = routes.iter().map(Self::stop_ids).collect();
+
+        let locked: Vec<usize> = routes
+            .iter()
+            .map(|route| plan.locked_stops(route.vehicle.id))
+            .collect();
+
+        if routes
+            .iter()
+            .zip(locked.iter())
+            .any(|(route, locked)| *locked > route.stops.len())
+        {
+            return Err(InvalidSolutionError::new());
+        }
+
+        while let Some(insertion) = self.cheapest_insertion(&routes, &locked, &new_stops) {
+            let stop = new_stops.remove(insertion.stop_index);
+
+            routes[insertion.route_index]
+                .insert_stop(stop, insertion.position, insertion.cost)
+                .map_err(|_| InvalidSolutionError::new())?;
+        }
+
+        if reoptimize {
+            for (route, locked) in routes.iter_mut().zip(locked.iter()) {
+                if let Some(reoptimized) = self.reoptimize_tail(route, *locked) {
+                    *route = reoptimized;
+                }
+            }
+        }
+
+        let changed_vehicles = routes
+            .iter()
+            .zip(original.iter())
+            .filter(|(route, original)| Self::stop_ids(route) != **original)
+            .map(|(route, _)| route.vehicle.id)
+            .collect();
+
+        self.route_service.update_routes(routes);
+
+        Ok(ReoptimizationResult {
+            solution: Solution::new(
+                self.route_service.get_all_routes(),
+                self.route_service.total_distance(),
+            ),
+            changed_vehicles,
+            unassigned_stops: new_stops.iter().map(|stop| stop.id).collect(),
+        })
+    }
+}
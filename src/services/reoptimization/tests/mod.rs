@@ -0,0 +1,2 @@
+#[cfg(test)]
+pub mod reoptimization_service_test;
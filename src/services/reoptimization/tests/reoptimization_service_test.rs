@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::{
+        distance::distance_service::DistanceMatrix,
+        reoptimization::{live_plan::LivePlan, reoptimization_service::ReoptimizationService},
+    },
+    solvers::solution::Solution,
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+fn plan(routes: Vec<(u32, Vec<u32>)>, visited: Vec<(u32, usize)>) -> LivePlan {
+    LivePlan::new(
+        Solution {
+            total_distance: 0.0,
+            result: HashMap::from_iter(routes),
+        },
+        HashMap::from_iter(visited),
+    )
+}
+
+#[rstest]
+fn new_stop_is_inserted_at_cheapest_position(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut service = ReoptimizationService::new(vehicle_factory(2), &distances, stops);
+    let plan = plan(vec![(0, vec![0, 2, 3, 0]), (1, vec![0, 0])], vec![(0, 2)]);
+
+    let result = service.insert_stops(&plan, &[1], false).unwrap();
+
+    assert_eq!(result.solution.result[&0], vec![0, 2, 3, 1, 0]);
+    assert_eq!(result.solution.total_distance, 8.0);
+    assert_eq!(result.changed_vehicles, vec![0]);
+    assert!(result.unassigned_stops.is_empty());
+}
+
+#[rstest]
+fn visited_prefix_is_not_changed(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut service = ReoptimizationService::new(vehicle_factory(2), &distances, stops);
+    let plan = plan(vec![(0, vec![0, 2, 3, 0]), (1, vec![0, 0])], vec![(0, 4)]);
+
+    let result = service.insert_stops(&plan, &[1], false).unwrap();
+
+    assert_eq!(result.solution.result[&0], vec![0, 2, 3, 0]);
+    assert_eq!(result.solution.result[&1], vec![0, 1, 0]);
+    assert_eq!(result.changed_vehicles, vec![1]);
+}
+
+#[rstest]
+#[case(1, 8.0, vec![0])]
+#[case(2, 8.0, vec![0])]
+#[case(3, 12.0, vec![])]
+fn unlocked_tail_is_reoptimized(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+    #[case] visited: usize,
+    #[case] total_distance: f64,
+    #[case] changed_vehicles: Vec<u32>,
+) {
+    let mut service = ReoptimizationService::new(vehicle_factory(2), &distances, stops);
+    let plan = plan(vec![(0, vec![0, 1, 2, 3, 0])], vec![(0, visited)]);
+
+    let result = service.insert_stops(&plan, &[], true).unwrap();
+
+    assert_eq!(result.solution.total_distance, total_distance);
+    assert_eq!(result.changed_vehicles, changed_vehicles);
+    assert_eq!(
+        result.solution.result[&0][..visited],
+        [0, 1, 2, 3][..visited]
+    );
+}
+
+#[rstest]
+fn infeasible_stop_is_reported_unassigned(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut service = ReoptimizationService::new(vehicle_factory(2), &distances, stops);
+    let plan = plan(vec![(0, vec![0, 2, 3, 1, 0])], vec![]);
+
+    let result = service.insert_stops(&plan, &[4], false).unwrap();
+
+    assert_eq!(result.unassigned_stops, vec![4]);
+    assert!(result.changed_vehicles.is_empty());
+}
+
+#[rstest]
+#[case(vec![(0, vec![0, 2, 3, 1, 0])], vec![], vec![1])]
+#[case(vec![(0, vec![0, 2, 3, 0])], vec![], vec![9])]
+#[case(vec![(0, vec![0, 2, 3, 0])], vec![(0, 5)], vec![1])]
+fn invalid_plan_is_rejected(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+    #[case] routes: Vec<(u32, Vec<u32>)>,
+    #[case] visited: Vec<(u32, usize)>,
+    #[case] new_stop_ids: Vec<u32>,
+) {
+    let mut service = ReoptimizationService::new(vehicle_factory(2), &distances, stops);
+    let plan = plan(routes, visited);
+
+    assert!(service.insert_stops(&plan, &new_stop_ids, false).is_err());
+}
@@ -1,13 +1,17 @@
 use rand::{seq::IteratorRandom, Rng};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::BTreeMap;
 
 use crate::{
     domain::{route::Route, stop::Stop, vehicle::Vehicle},
-    errors::vehicle::vehicle_overload::VehicleOverloadError,
-    services::distance::distance_service::{DistanceMatrix, DistanceService},
+    errors::{
+        solver::invalid_solution::InvalidSolutionError,
+        vehicle::vehicle_overload::VehicleOverloadError,
+    },
+    services::distance::{distance_oracle::IntoDistanceOracle, distance_service::DistanceService},
+    solvers::solution::Solution,
 };
 
-pub type StopMap = HashMap<u32, Stop>;
+pub type StopMap = BTreeMap<u32, Stop>;
 pub type RouteMap = BTreeMap<u32, Route>;
 
 pub struct RouteService {
@@ -20,7 +24,7 @@ pub struct RouteService {
 impl RouteService {
     pub fn new(
         vehicles: Vec<Vehicle>,
-        distances: &DistanceMatrix,
+        distances: impl IntoDistanceOracle,
         stops: Vec<Stop>,
     ) -> RouteService {
         RouteService {
@@ -75,6 +79,12 @@ impl RouteService {
         &self.routes
     }
 
+    pub fn update_routes(&mut self, routes: Vec<Route>) {
+        for route in routes {
+            self.routes.insert(route.vehicle.id, route);
+        }
+    }
+
     pub fn get_vehicles(&self) -> Vec<&Vehicle> {
         self.routes.values().map(|x| &x.vehicle).collect()
     }
@@ -118,6 +128,80 @@ impl RouteService {
         route.add_stop(new_stop, distance)
     }
 
+    fn load_route(
+        &mut self,
+        vehicle_id: u32,
+        stop_ids: &[u32],
+    ) -> Result<(), InvalidSolutionError> {
+        let inner_stop_ids = match stop_ids {
+            [] => stop_ids,
+            [first, inner @ .., last] if *first == 0 && *last == 0 => inner,
+            _ => return Err(InvalidSolutionError::new()),
+        };
+
+        for stop_id in inner_stop_ids {
+            if !self.available_stops.contains_key(stop_id) {
+                return Err(InvalidSolutionError::new());
+            }
+
+            self.assign_stop_to_route(vehicle_id, *stop_id)
+                .map_err(|_| InvalidSolutionError::new())?;
+        }
+
+        Ok(())
+    }
+
+    fn complete_routes(&mut self, vehicle_ids: &[u32]) {
+        while self.has_available_stop().unwrap_or(false) {
+            for vehicle_id in vehicle_ids {
+                let stop_id = match self.get_nearest_stop(*vehicle_id) {
+                    None => continue,
+                    Some(stop) => stop.id,
+                };
+
+                self.assign_stop_to_route(*vehicle_id, stop_id).ok();
+            }
+        }
+    }
+
+    pub fn load_routes(&mut self, solution: &Solution) -> Result<(), InvalidSolutionError> {
+        if solution
+            .result
+            .keys()
+            .any(|vehicle_id| !self.routes.contains_key(vehicle_id))
+        {
+            return Err(InvalidSolutionError::new());
+        }
+
+        self.reset();
+        self.assign_starting_points()
+            .ok_or_else(InvalidSolutionError::new)?;
+
+        let vehicle_ids: Vec<u32> = self.routes.keys().cloned().collect();
+
+        for vehicle_id in vehicle_ids.iter() {
+            if let Some(stop_ids) = solution.result.get(vehicle_id) {
+                if let Err(error) = self.load_route(*vehicle_id, stop_ids) {
+                    self.reset();
+                    return Err(error);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn load_solution(&mut self, solution: &Solution) -> Result<(), InvalidSolutionError> {
+        self.load_routes(solution)?;
+
+        let vehicle_ids: Vec<u32> = self.routes.keys().cloned().collect();
+
+        self.complete_routes(&vehicle_ids);
+        self.assign_stop_points();
+
+        Ok(())
+    }
+
     pub fn assign_starting_points(&mut self) -> Option<()> {
         let starting_stop = self.available_stops.remove(&0)?;
 
@@ -170,6 +254,18 @@ impl RouteService {
         )
     }
 
+    pub fn get_alpha_nearest_stops(&self, vehicle_id: u32, alpha: f64) -> Option<Vec<&Stop>> {
+        let route = self.get_route(vehicle_id)?;
+        let current_stop = route.get_current_stop()?;
+
+        Some(
+            self.distance_service
+                .get_stops_within_alpha(current_stop, alpha, |stop| {
+                    self.is_stop_feasible(stop, route)
+                }),
+        )
+    }
+
     pub fn get_random_stop<R>(&self, vehicle_id: u32, rng: &mut R) -> Option<&Stop>
     where
         R: Rng + ?Sized,
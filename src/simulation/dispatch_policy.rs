@@ -0,0 +1,72 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    services::distance::distance_service::DistanceMatrix,
+    solvers::{
+        grasp::grasp_solver::GraspSolver, greedy::greedy_solver::GreedySolver, solution::Solution,
+    },
+};
+
+pub trait DispatchPolicy {
+    fn plan(
+        &mut self,
+        vehicles: Vec<Vehicle>,
+        distances: &DistanceMatrix,
+        stops: Vec<Stop>,
+    ) -> Solution;
+}
+
+pub struct GreedyPolicy;
+
+impl DispatchPolicy for GreedyPolicy {
+    fn plan(
+        &mut self,
+        vehicles: Vec<Vehicle>,
+        distances: &DistanceMatrix,
+        stops: Vec<Stop>,
+    ) -> Solution {
+        let mut solver = GreedySolver::new(vehicles, distances, stops);
+        solver.solve();
+
+        solver.solution
+    }
+}
+
+pub struct GraspPolicy {
+    rcl_size: usize,
+    max_improvement_times: u8,
+    rng: ChaCha8Rng,
+}
+
+impl GraspPolicy {
+    pub fn new(rcl_size: usize, max_improvement_times: u8, seed: u64) -> Self {
+        Self {
+            rcl_size,
+            max_improvement_times,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl DispatchPolicy for GraspPolicy {
+    fn plan(
+        &mut self,
+        vehicles: Vec<Vehicle>,
+        distances: &DistanceMatrix,
+        stops: Vec<Stop>,
+    ) -> Solution {
+        let mut solver = GraspSolver::new(
+            self.rcl_size,
+            vehicles,
+            distances,
+            self.max_improvement_times,
+            stops,
+            &mut self.rng,
+        );
+        solver.solve();
+
+        solver.solution
+    }
+}
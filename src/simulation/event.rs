@@ -0,0 +1,18 @@
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum EventKind {
+    OrderArrival { stop_id: u32, due: Option<f64> },
+    Cancellation { stop_id: u32 },
+    VehiclePosition { vehicle_id: u32, stop_id: u32 },
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Event {
+    pub time: f64,
+    pub kind: EventKind,
+}
+
+impl Event {
+    pub fn new(time: f64, kind: EventKind) -> Self {
+        Self { time, kind }
+    }
+}
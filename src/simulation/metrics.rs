@@ -0,0 +1,10 @@
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct SimulationMetrics {
+    pub served_orders: usize,
+    pub cancelled_orders: usize,
+    pub unserved_orders: usize,
+    pub late_orders: usize,
+    pub total_distance: f64,
+    pub total_lateness: f64,
+    pub decision_epochs: usize,
+}
@@ -0,0 +1,8 @@
+pub mod dispatch_policy;
+pub mod event;
+pub mod metrics;
+pub mod scenario;
+pub mod simulator;
+mod vehicle_state;
+
+mod tests;
@@ -0,0 +1,133 @@
+use std::{error::Error, fs, path::Path, str::FromStr};
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    errors::simulation::invalid_scenario::InvalidScenarioError,
+    services::distance::distance_service::DistanceMatrix,
+};
+
+use super::event::{Event, EventKind};
+
+const DEPOT_ID: u32 = 0;
+
+#[derive(Default)]
+pub struct Scenario {
+    pub vehicles: Vec<Vehicle>,
+    pub stops: Vec<Stop>,
+    pub distances: DistanceMatrix,
+    pub events: Vec<Event>,
+}
+
+impl Scenario {
+    pub fn from_file(path: &Path) -> Result<Self, Box<dyn Error>> {
+        Ok(fs::read_to_string(path)?.parse()?)
+    }
+
+    pub fn depot(&self) -> Option<Stop> {
+        self.stop(DEPOT_ID)
+    }
+
+    pub fn stop(&self, stop_id: u32) -> Option<Stop> {
+        self.stops.iter().find(|stop| stop.id == stop_id).copied()
+    }
+
+    fn parse_line(&mut self, fields: &[&str]) -> Option<()> {
+        let number = |index: usize| fields.get(index)?.parse::<f64>().ok();
+        let id = |index: usize| fields.get(index)?.parse::<u32>().ok();
+
+        match (fields.first()?, fields.len()) {
+            (&"vehicle", 3) => self.vehicles.push(Vehicle::new(id(1)?, id(2)?)),
+            (&"stop", 3) => self.stops.push(Stop::new(id(1)?, id(2)?)),
+            (&"distance", 4) => {
+                self.distances.insert((id(1)?, id(2)?), number(3)?);
+            }
+            (&"order", 3 | 4) => self.events.push(Event::new(
+                number(1)?,
+                EventKind::OrderArrival {
+                    stop_id: id(2)?,
+                    due: match fields.len() {
+                        4 => Some(number(3)?),
+                        _ => None,
+                    },
+                },
+            )),
+            (&"cancel", 3) => self.events.push(Event::new(
+                number(1)?,
+                EventKind::Cancellation { stop_id: id(2)? },
+            )),
+            (&"position", 4) => self.events.push(Event::new(
+                number(1)?,
+                EventKind::VehiclePosition {
+                    vehicle_id: id(2)?,
+                    stop_id: id(3)?,
+                },
+            )),
+            _ => return None,
+        }
+
+        Some(())
+    }
+
+    fn is_consistent(&self) -> bool {
+        let has_stop = |stop_id: &u32| self.stop(*stop_id).is_some();
+        let has_vehicle = |vehicle_id: &u32| {
+            self.vehicles
+                .iter()
+                .any(|vehicle| vehicle.id == *vehicle_id)
+        };
+
+        let has_distance = |from: &Stop, to: &Stop| {
+            from.id == to.id || self.distances.contains_key(&(from.id, to.id))
+        };
+
+        self.depot().is_some()
+            && self
+                .distances
+                .keys()
+                .all(|(from, to)| has_stop(from) && has_stop(to))
+            && self
+                .stops
+                .iter()
+                .all(|from| self.stops.iter().all(|to| has_distance(from, to)))
+            && self.events.iter().all(|event| match event.kind {
+                EventKind::OrderArrival { stop_id, .. } | EventKind::Cancellation { stop_id } => {
+                    stop_id != DEPOT_ID && has_stop(&stop_id)
+                }
+                EventKind::VehiclePosition {
+                    vehicle_id,
+                    stop_id,
+                } => has_vehicle(&vehicle_id) && has_stop(&stop_id),
+            })
+    }
+}
+
+impl FromStr for Scenario {
+    type Err = InvalidScenarioError;
+
+    fn from_str(content: &str) -> Result<Self, Self::Err> {
+        let mut scenario = Scenario::default();
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default();
+            let fields: Vec<&str> = line.split_whitespace().collect();
+
+            if fields.is_empty() {
+                continue;
+            }
+
+            scenario
+                .parse_line(&fields)
+                .ok_or_else(|| InvalidScenarioError::new(index + 1))?;
+        }
+
+        if !scenario.is_consistent() {
+            return Err(InvalidScenarioError::new(0));
+        }
+
+        scenario
+            .events
+            .sort_by(|event1, event2| event1.time.total_cmp(&event2.time));
+
+        Ok(scenario)
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    services::{
+        distance::distance_service::DistanceMatrix,
+        reoptimization::{live_plan::LivePlan, reoptimization_service::ReoptimizationService},
+    },
+    solvers::solution::Solution,
+};
+
+use super::{
+    dispatch_policy::DispatchPolicy,
+    event::{Event, EventKind},
+    metrics::SimulationMetrics,
+    scenario::Scenario,
+    vehicle_state::VehicleState,
+};
+
+pub struct Simulator<P: DispatchPolicy> {
+    scenario: Scenario,
+    policy: P,
+    vehicles: Vec<VehicleState>,
+    pending: HashMap<u32, Option<f64>>,
+    served: HashSet<u32>,
+    pub metrics: SimulationMetrics,
+}
+
+impl<P: DispatchPolicy> Simulator<P> {
+    pub fn new(scenario: Scenario, policy: P) -> Self {
+        let depot_id = scenario.depot().map_or(0, |depot| depot.id);
+        let vehicles = scenario
+            .vehicles
+            .iter()
+            .map(|vehicle| VehicleState::new(*vehicle, depot_id))
+            .collect();
+
+        Self {
+            scenario,
+            policy,
+            vehicles,
+            pending: Default::default(),
+            served: Default::default(),
+            metrics: Default::default(),
+        }
+    }
+
+    fn distance(&self, from: u32, to: u32) -> f64 {
+        match from == to {
+            true => 0.0,
+            false => self
+                .scenario
+                .distances
+                .get(&(from, to))
+                .copied()
+                .unwrap_or(f64::INFINITY),
+        }
+    }
+
+    fn advance(&mut self, time: f64) {
+        for index in 0..self.vehicles.len() {
+            while let Some(next) = self.vehicles[index].plan.front().copied() {
+                let state = &self.vehicles[index];
+                let arrival = state.clock + self.distance(state.location, next);
+
+                if arrival > time {
+                    break;
+                }
+
+                let travelled = arrival - state.clock;
+                let usage = self.scenario.stop(next).map_or(0, |stop| stop.usage);
+
+                let state = &mut self.vehicles[index];
+                state.plan.pop_front();
+                state.distance += travelled;
+                state.clock = arrival;
+                state.location = next;
+                state.load += usage;
+
+                self.serve(next, arrival);
+            }
+        }
+    }
+
+    fn serve(&mut self, stop_id: u32, arrival: f64) {
+        let Some(due) = self.pending.remove(&stop_id) else {
+            return;
+        };
+
+        self.served.insert(stop_id);
+        self.metrics.served_orders += 1;
+
+        if let Some(due) = due {
+            let lateness = (arrival - due).max(0.0);
+
+            if lateness > 0.0 {
+                self.metrics.late_orders += 1;
+                self.metrics.total_lateness += lateness;
+            }
+        }
+    }
+
+    fn apply(&mut self, event: &Event) {
+        match event.kind {
+            EventKind::OrderArrival { stop_id, due } => {
+                if !self.served.contains(&stop_id) {
+                    self.pending.insert(stop_id, due);
+                }
+            }
+            EventKind::Cancellation { stop_id } => {
+                if self.pending.remove(&stop_id).is_some() {
+                    self.metrics.cancelled_orders += 1;
+                }
+            }
+            EventKind::VehiclePosition {
+                vehicle_id,
+                stop_id,
+            } => {
+                if let Some(state) = self
+                    .vehicles
+                    .iter_mut()
+                    .find(|state| state.vehicle.id == vehicle_id)
+                {
+                    state.location = stop_id;
+                    state.clock = state.clock.max(event.time);
+                }
+            }
+        }
+    }
+
+    fn depot_id(&self) -> u32 {
+        self.scenario.depot().map_or(0, |depot| depot.id)
+    }
+
+    fn starts(&self) -> HashMap<u32, Stop> {
+        let depot_id = self.depot_id();
+        let mut locations = HashSet::new();
+
+        self.vehicles
+            .iter()
+            .filter(|state| state.location != depot_id)
+            .filter(|state| !self.pending.contains_key(&state.location))
+            .filter(|state| locations.insert(state.location))
+            .map(|state| (state.vehicle.id, Stop::new(state.location, 0)))
+            .collect()
+    }
+
+    fn remaining_problem(&self, starts: &HashMap<u32, Stop>) -> (Vec<Stop>, DistanceMatrix) {
+        let stops: Vec<Stop> = self
+            .scenario
+            .depot()
+            .into_iter()
+            .chain(
+                self.scenario
+                    .stops
+                    .iter()
+                    .filter(|stop| self.pending.contains_key(&stop.id))
+                    .copied(),
+            )
+            .collect();
+
+        let stop_ids: HashSet<u32> = stops
+            .iter()
+            .chain(starts.values())
+            .map(|stop| stop.id)
+            .collect();
+
+        let distances = self
+            .scenario
+            .distances
+            .iter()
+            .filter(|((from, to), _)| stop_ids.contains(from) && stop_ids.contains(to))
+            .map(|(key, distance)| (*key, *distance))
+            .collect();
+
+        (stops, distances)
+    }
+
+    fn start_from_locations(
+        &self,
+        solution: &Solution,
+        vehicles: Vec<Vehicle>,
+        distances: &DistanceMatrix,
+        stops: &[Stop],
+        starts: &HashMap<u32, Stop>,
+    ) -> Option<Solution> {
+        let depot_id = self.depot_id();
+        let mut routes = HashMap::new();
+        let mut visited = HashMap::new();
+
+        for vehicle in vehicles.iter() {
+            let start = starts.get(&vehicle.id).map(|stop| stop.id);
+            let planned = solution
+                .result
+                .get(&vehicle.id)
+                .into_iter()
+                .flatten()
+                .filter(|stop_id| self.pending.contains_key(stop_id));
+
+            let route: Vec<u32> = std::iter::once(depot_id)
+                .chain(start)
+                .chain(planned.copied())
+                .chain(std::iter::once(depot_id))
+                .collect();
+
+            routes.insert(vehicle.id, route);
+            visited.insert(vehicle.id, if start.is_some() { 2 } else { 1 });
+        }
+
+        let assigned: HashSet<u32> = routes.values().flatten().copied().collect();
+        let unassigned: Vec<u32> = stops
+            .iter()
+            .map(|stop| stop.id)
+            .filter(|stop_id| !assigned.contains(stop_id))
+            .collect();
+
+        let all_stops = stops.iter().chain(starts.values()).copied().collect();
+        let plan = LivePlan::new(
+            Solution {
+                total_distance: solution.total_distance,
+                result: routes,
+            },
+            visited,
+        );
+
+        ReoptimizationService::new(vehicles, distances, all_stops)
+            .insert_stops(&plan, &unassigned, true)
+            .ok()
+            .map(|result| result.solution)
+    }
+
+    fn replan(&mut self, time: f64) {
+        self.metrics.decision_epochs += 1;
+
+        for state in self.vehicles.iter_mut() {
+            state.clock = state.clock.max(time);
+            state.plan.clear();
+        }
+
+        if self.pending.is_empty() {
+            return;
+        }
+
+        let starts = self.starts();
+        let (stops, distances) = self.remaining_problem(&starts);
+        let vehicles: Vec<Vehicle> = self
+            .vehicles
+            .iter()
+            .map(|state| state.remaining_vehicle())
+            .collect();
+
+        let solution = self
+            .policy
+            .plan(vehicles.clone(), &distances, stops.clone());
+        let solution = self
+            .start_from_locations(&solution, vehicles, &distances, &stops, &starts)
+            .unwrap_or(solution);
+
+        for state in self.vehicles.iter_mut() {
+            if let Some(stop_ids) = solution.result.get(&state.vehicle.id) {
+                state.plan = stop_ids
+                    .iter()
+                    .filter(|stop_id| self.pending.contains_key(stop_id))
+                    .copied()
+                    .collect();
+            }
+        }
+    }
+
+    fn finish(&mut self) {
+        self.advance(f64::INFINITY);
+
+        let depot_id = self.depot_id();
+
+        for index in 0..self.vehicles.len() {
+            let location = self.vehicles[index].location;
+            let distance = self.distance(location, depot_id);
+
+            let state = &mut self.vehicles[index];
+            state.distance += distance;
+            state.clock += distance;
+            state.location = depot_id;
+        }
+
+        self.metrics.total_distance = self.vehicles.iter().map(|state| state.distance).sum();
+        self.metrics.unserved_orders = self.pending.len();
+    }
+
+    pub fn run(&mut self) -> &SimulationMetrics {
+        let events = self.scenario.events.clone();
+        let mut index = 0;
+
+        while index < events.len() {
+            let time = events[index].time;
+            self.advance(time);
+
+            while index < events.len() && events[index].time == time {
+                self.apply(&events[index]);
+                index += 1;
+            }
+
+            self.replan(time);
+        }
+
+        self.finish();
+
+        &self.metrics
+    }
+}
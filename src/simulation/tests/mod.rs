@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod scenario_test;
+
+#[cfg(test)]
+pub mod simulator_test;
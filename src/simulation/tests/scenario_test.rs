@@ -0,0 +1,85 @@
+use std::{env, fs};
+
+use rstest::rstest;
+
+use crate::simulation::{
+    event::{Event, EventKind},
+    scenario::Scenario,
+};
+
+use crate::tests::fixtures::simulation_fixture::{scenario_factory, ScenarioFactory};
+
+#[rstest]
+fn scenario_is_parsed(scenario_factory: ScenarioFactory) {
+    let content = scenario_factory(
+        "\
+# orders arrive out of order
+order 5 1 9.5
+order 0 2
+cancel 6 1
+position 7 0 3
+",
+    );
+
+    let scenario: Scenario = content.parse().unwrap();
+
+    assert_eq!(scenario.vehicles.len(), 1);
+    assert_eq!(scenario.stops.len(), 5);
+    assert_eq!(scenario.distances.len(), 20);
+    assert_eq!(
+        scenario.events,
+        vec![
+            Event::new(
+                0.0,
+                EventKind::OrderArrival {
+                    stop_id: 2,
+                    due: None
+                }
+            ),
+            Event::new(
+                5.0,
+                EventKind::OrderArrival {
+                    stop_id: 1,
+                    due: Some(9.5)
+                }
+            ),
+            Event::new(6.0, EventKind::Cancellation { stop_id: 1 }),
+            Event::new(
+                7.0,
+                EventKind::VehiclePosition {
+                    vehicle_id: 0,
+                    stop_id: 3
+                }
+            ),
+        ]
+    );
+}
+
+#[rstest]
+fn malformed_line_is_reported(scenario_factory: ScenarioFactory) {
+    let content = scenario_factory("order 0\n");
+
+    let error = content.parse::<Scenario>().err().unwrap();
+
+    assert_eq!(error.line, 27);
+}
+
+#[rstest]
+#[case("order 0 9\n")]
+#[case("order 0 0\n")]
+#[case("position 0 5 1\n")]
+#[case("stop 5 1\norder 0 5\n")]
+fn inconsistent_scenario_is_rejected(scenario_factory: ScenarioFactory, #[case] events: &str) {
+    assert!(scenario_factory(events).parse::<Scenario>().is_err());
+}
+
+#[rstest]
+fn scenario_is_read_from_file(scenario_factory: ScenarioFactory) {
+    let path = env::temp_dir().join("vrp_solver_scenario_test.txt");
+    fs::write(&path, scenario_factory("order 0 1\n")).unwrap();
+
+    let scenario = Scenario::from_file(&path).unwrap();
+    fs::remove_file(&path).ok();
+
+    assert_eq!(scenario.events.len(), 1);
+}
@@ -0,0 +1,101 @@
+use rstest::rstest;
+
+use crate::simulation::{
+    dispatch_policy::{GraspPolicy, GreedyPolicy},
+    scenario::Scenario,
+    simulator::Simulator,
+};
+
+use crate::tests::fixtures::simulation_fixture::{scenario_factory, ScenarioFactory};
+
+fn scenario(scenario_factory: &ScenarioFactory, events: &str) -> Scenario {
+    scenario_factory(events).parse().unwrap()
+}
+
+#[rstest]
+fn static_orders_are_served(scenario_factory: ScenarioFactory) {
+    let scenario = scenario(&scenario_factory, "order 0 1\norder 0 2\norder 0 3\n");
+    let mut simulator = Simulator::new(scenario, GreedyPolicy);
+
+    let metrics = simulator.run();
+
+    assert_eq!(metrics.served_orders, 3);
+    assert_eq!(metrics.unserved_orders, 0);
+    assert_eq!(metrics.decision_epochs, 1);
+    assert_eq!(metrics.total_distance, 8.0);
+}
+
+#[rstest]
+fn cancelled_order_is_not_served(scenario_factory: ScenarioFactory) {
+    let scenario = scenario(
+        &scenario_factory,
+        "order 0 1\norder 0 2\norder 0 3\ncancel 0.5 3\n",
+    );
+    let mut simulator = Simulator::new(scenario, GreedyPolicy);
+
+    let metrics = simulator.run();
+
+    assert_eq!(metrics.served_orders, 2);
+    assert_eq!(metrics.cancelled_orders, 1);
+    assert_eq!(metrics.decision_epochs, 2);
+    assert_eq!(metrics.total_distance, 8.0);
+}
+
+#[rstest]
+fn late_order_is_measured(scenario_factory: ScenarioFactory) {
+    let scenario = scenario(&scenario_factory, "order 0 1 1.0\norder 0 2\norder 0 3\n");
+    let mut simulator = Simulator::new(scenario, GreedyPolicy);
+
+    let metrics = simulator.run();
+
+    assert_eq!(metrics.late_orders, 1);
+    assert_eq!(metrics.total_lateness, 5.0);
+}
+
+#[rstest]
+fn order_arriving_later_is_dispatched_from_vehicle_position(scenario_factory: ScenarioFactory) {
+    let scenario = scenario(&scenario_factory, "order 0 2\norder 0 3\norder 10 1\n");
+    let mut simulator = Simulator::new(scenario, GreedyPolicy);
+
+    let metrics = simulator.run();
+
+    assert_eq!(metrics.served_orders, 3);
+    assert_eq!(metrics.decision_epochs, 2);
+    assert_eq!(metrics.total_distance, 8.0);
+}
+
+#[rstest]
+fn route_is_planned_from_vehicle_position(scenario_factory: ScenarioFactory) {
+    let scenario = scenario(&scenario_factory, "position 0 0 1\norder 0 2\norder 0 3\n");
+    let mut simulator = Simulator::new(scenario, GreedyPolicy);
+
+    let metrics = simulator.run();
+
+    assert_eq!(metrics.served_orders, 2);
+    assert_eq!(metrics.total_distance, 6.0);
+}
+
+#[rstest]
+fn unserviceable_order_is_reported(scenario_factory: ScenarioFactory) {
+    let scenario = scenario(&scenario_factory, "order 0 1\norder 0 4\n");
+    let mut simulator = Simulator::new(scenario, GreedyPolicy);
+
+    let metrics = simulator.run();
+
+    assert_eq!(metrics.served_orders, 1);
+    assert_eq!(metrics.unserved_orders, 1);
+}
+
+#[rstest]
+fn grasp_policy_serves_orders(scenario_factory: ScenarioFactory) {
+    let scenario = scenario(
+        &scenario_factory,
+        "order 0 1\norder 0 2\norder 4 3\nposition 4 0 0\n",
+    );
+    let mut simulator = Simulator::new(scenario, GraspPolicy::new(2, 3, 0));
+
+    let metrics = simulator.run();
+
+    assert_eq!(metrics.served_orders, 3);
+    assert_eq!(metrics.unserved_orders, 0);
+}
@@ -0,0 +1,32 @@
+use std::collections::VecDeque;
+
+use crate::domain::vehicle::Vehicle;
+
+pub(crate) struct VehicleState {
+    pub(crate) vehicle: Vehicle,
+    pub(crate) location: u32,
+    pub(crate) clock: f64,
+    pub(crate) load: u32,
+    pub(crate) distance: f64,
+    pub(crate) plan: VecDeque<u32>,
+}
+
+impl VehicleState {
+    pub(crate) fn new(vehicle: Vehicle, location: u32) -> Self {
+        Self {
+            vehicle,
+            location,
+            clock: Default::default(),
+            load: Default::default(),
+            distance: Default::default(),
+            plan: Default::default(),
+        }
+    }
+
+    pub(crate) fn remaining_vehicle(&self) -> Vehicle {
+        Vehicle::new(
+            self.vehicle.id,
+            self.vehicle.capacity.saturating_sub(self.load),
+        )
+    }
+}
@@ -0,0 +1,367 @@
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    errors::solver::instance_too_large::InstanceTooLargeError,
+    local_search::held_karp::MAX_EXACT_STOPS,
+    lower_bound::{
+        distance_bound::{assignment_bound, distance_table, spanning_tree_bound, DistanceTable},
+        vehicle_bound::is_serviceable,
+    },
+    services::{
+        distance::{distance_oracle::IntoDistanceOracle, distance_service::DistanceService},
+        route::route_service::RouteMap,
+    },
+    solvers::solution::Solution,
+};
+
+const DEPOT_ID: u32 = 0;
+const BOUND_TOLERANCE: f64 = 1e-9;
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum ExactSolveStatus {
+    #[default]
+    NotSolved,
+    Optimal,
+    /// The search stopped at the node limit; `solution` holds the best routes found, if any.
+    NodeLimitReached,
+    Infeasible,
+    MissingDepot,
+}
+
+pub struct BranchAndBoundSolver {
+    pub solution: Solution,
+    pub status: ExactSolveStatus,
+    depot: Option<Stop>,
+    customers: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: DistanceTable,
+    max_nodes: u64,
+    explored_nodes: u64,
+}
+
+impl BranchAndBoundSolver {
+    pub fn new(
+        vehicles: Vec<Vehicle>,
+        distances: impl IntoDistanceOracle,
+        stops: Vec<Stop>,
+        max_nodes: u64,
+    ) -> Result<Self, InstanceTooLargeError> {
+        let depot = stops.iter().find(|stop| stop.id == DEPOT_ID).copied();
+        let distance_service = DistanceService::new(stops.clone(), distances);
+
+        let customers: Vec<Stop> = stops
+            .iter()
+            .filter(|stop| stop.id != DEPOT_ID)
+            .filter(|stop| is_serviceable(stop, depot.as_ref(), &vehicles))
+            .copied()
+            .collect();
+
+        if customers.len() > MAX_EXACT_STOPS {
+            return Err(InstanceTooLargeError::new());
+        }
+
+        let nodes: Vec<Stop> = depot.iter().chain(customers.iter()).copied().collect();
+
+        let distances = distance_table(&nodes, &distance_service);
+
+        Ok(Self {
+            depot,
+            vehicles,
+            customers,
+            distances,
+            max_nodes,
+            status: Default::default(),
+            explored_nodes: Default::default(),
+            solution: Solution::default(),
+        })
+    }
+
+    fn customer_bit(customer: usize) -> u64 {
+        1 << (customer - 1)
+    }
+
+    fn residual_capacity(route: &Route, depot: &Stop) -> u32 {
+        route
+            .vehicle
+            .capacity
+            .saturating_sub(route.vehicle.usage() + depot.usage + 1)
+    }
+
+    fn has_enough_capacity(&self, routes: &[Route], vehicle_index: usize, unvisited: u64) -> bool {
+        let depot = match &self.depot {
+            Some(depot) => depot,
+            None => return false,
+        };
+
+        let demand: u32 = (1..=self.customers.len())
+            .filter(|customer| unvisited & Self::customer_bit(*customer) != 0)
+            .map(|customer| self.customers[customer - 1].usage)
+            .sum();
+
+        let capacity: u32 = routes[vehicle_index..]
+            .iter()
+            .map(|route| Self::residual_capacity(route, depot))
+            .sum();
+
+        demand <= capacity
+    }
+
+    fn respects_symmetry(
+        &self,
+        first_customers: &[Option<usize>],
+        vehicle_index: usize,
+        customer: usize,
+    ) -> bool {
+        if vehicle_index == 0 {
+            return true;
+        }
+
+        if self.vehicles[vehicle_index].capacity != self.vehicles[vehicle_index - 1].capacity {
+            return true;
+        }
+
+        match first_customers[vehicle_index - 1] {
+            Some(previous) => customer > previous,
+            None => false,
+        }
+    }
+
+    fn update_solution(
+        &mut self,
+        routes: &[Route],
+        vehicle_index: usize,
+        current: usize,
+        cost: f64,
+    ) {
+        let depot = match self.depot {
+            Some(depot) => depot,
+            None => return,
+        };
+
+        let total_distance = cost + self.distances[current][0];
+
+        if total_distance >= self.solution.total_distance {
+            return;
+        }
+
+        let mut closed_routes = routes.to_vec();
+
+        for (index, route) in closed_routes.iter_mut().enumerate().skip(vehicle_index) {
+            let distance = match index == vehicle_index {
+                true => self.distances[current][0],
+                false => 0.0,
+            };
+
+            if route.add_stop(depot, distance).is_err() {
+                return;
+            }
+        }
+
+        let route_map: RouteMap = closed_routes
+            .into_iter()
+            .map(|route| (route.vehicle.id, route))
+            .collect();
+
+        self.solution = Solution::new(&route_map, total_distance);
+    }
+
+    fn remaining_bound(&self, vehicle_index: usize, current: usize, unvisited: u64) -> f64 {
+        // The remaining routes are tours through a merged depot, which is left either from
+        // `current` or from the depot and always entered at the depot.
+        let nodes: Vec<usize> = std::iter::once(0)
+            .chain(
+                (1..=self.customers.len())
+                    .filter(|customer| unvisited & Self::customer_bit(*customer) != 0),
+            )
+            .collect();
+
+        let table: DistanceTable = nodes
+            .iter()
+            .map(|from| {
+                nodes
+                    .iter()
+                    .map(|to| match from {
+                        0 => self.distances[current][*to].min(self.distances[0][*to]),
+                        _ => self.distances[*from][*to],
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let routes = self.vehicles.len() - vehicle_index;
+
+        assignment_bound(&table, 1).max(spanning_tree_bound(&table, 1, routes))
+    }
+
+    fn candidates(&self, route: &Route, current: usize, unvisited: u64) -> Vec<usize> {
+        let mut candidates: Vec<usize> = (1..=self.customers.len())
+            .filter(|customer| unvisited & Self::customer_bit(*customer) != 0)
+            .filter(|customer| route.can_add_stop(&self.customers[customer - 1]))
+            .collect();
+
+        candidates.sort_by(|customer1, customer2| {
+            self.distances[current][*customer1]
+                .partial_cmp(&self.distances[current][*customer2])
+                .unwrap()
+        });
+
+        candidates
+    }
+
+    fn branch(
+        &mut self,
+        routes: &mut [Route],
+        first_customers: &mut [Option<usize>],
+        vehicle_index: usize,
+        current: usize,
+        unvisited: u64,
+        cost: f64,
+    ) {
+        self.explored_nodes += 1;
+
+        if self.explored_nodes > self.max_nodes {
+            return;
+        }
+
+        let bound = cost + self.remaining_bound(vehicle_index, current, unvisited);
+
+        if bound >= self.solution.total_distance - BOUND_TOLERANCE {
+            return;
+        }
+
+        if unvisited == 0 {
+            self.update_solution(routes, vehicle_index, current, cost);
+            return;
+        }
+
+        if !self.has_enough_capacity(routes, vehicle_index, unvisited) {
+            return;
+        }
+
+        for customer in self.candidates(&routes[vehicle_index], current, unvisited) {
+            if current == 0 && !self.respects_symmetry(first_customers, vehicle_index, customer) {
+                continue;
+            }
+
+            let distance = self.distances[current][customer];
+
+            if routes[vehicle_index]
+                .add_stop(self.customers[customer - 1], distance)
+                .is_err()
+            {
+                continue;
+            }
+
+            if current == 0 {
+                first_customers[vehicle_index] = Some(customer);
+            }
+
+            self.branch(
+                routes,
+                first_customers,
+                vehicle_index,
+                customer,
+                unvisited & !Self::customer_bit(customer),
+                cost + distance,
+            );
+
+            if current == 0 {
+                first_customers[vehicle_index] = None;
+            }
+
+            let last_index = routes[vehicle_index].stops.len() - 1;
+            routes[vehicle_index].remove_stop(last_index, distance);
+        }
+
+        self.close_route(
+            routes,
+            first_customers,
+            vehicle_index,
+            current,
+            unvisited,
+            cost,
+        );
+    }
+
+    fn close_route(
+        &mut self,
+        routes: &mut [Route],
+        first_customers: &mut [Option<usize>],
+        vehicle_index: usize,
+        current: usize,
+        unvisited: u64,
+        cost: f64,
+    ) {
+        let depot = match self.depot {
+            Some(depot) => depot,
+            None => return,
+        };
+
+        if vehicle_index + 1 >= routes.len() {
+            return;
+        }
+
+        let distance = self.distances[current][0];
+
+        if routes[vehicle_index].add_stop(depot, distance).is_err() {
+            return;
+        }
+
+        self.branch(
+            routes,
+            first_customers,
+            vehicle_index + 1,
+            0,
+            unvisited,
+            cost + distance,
+        );
+
+        let last_index = routes[vehicle_index].stops.len() - 1;
+        routes[vehicle_index].remove_stop(last_index, distance);
+    }
+
+    pub fn is_optimal(&self) -> bool {
+        self.status == ExactSolveStatus::Optimal
+    }
+
+    pub fn solve(&mut self) -> ExactSolveStatus {
+        self.solution = Solution::default();
+
+        let depot = match self.depot {
+            Some(depot) => depot,
+            None => {
+                self.status = ExactSolveStatus::MissingDepot;
+                return self.status;
+            }
+        };
+
+        let mut routes: Vec<Route> = self
+            .vehicles
+            .iter()
+            .map(|vehicle| {
+                let mut route = Route::new(*vehicle);
+                route.add_stop(depot, 0.0).ok();
+                route
+            })
+            .collect();
+
+        let mut first_customers = vec![None; routes.len()];
+        let unvisited = (1..=self.customers.len()).fold(0, |unvisited, customer| {
+            unvisited | Self::customer_bit(customer)
+        });
+
+        self.explored_nodes = 0;
+
+        self.branch(&mut routes, &mut first_customers, 0, 0, unvisited, 0.0);
+
+        self.status = match (
+            self.explored_nodes > self.max_nodes,
+            self.solution.result.is_empty(),
+        ) {
+            (true, _) => ExactSolveStatus::NodeLimitReached,
+            (false, true) => ExactSolveStatus::Infeasible,
+            (false, false) => ExactSolveStatus::Optimal,
+        };
+
+        self.status
+    }
+}
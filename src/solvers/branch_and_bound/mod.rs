@@ -0,0 +1,2 @@
+pub mod branch_and_bound_solver;
+mod tests;
@@ -0,0 +1,166 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::domain::stop::Stop;
+use crate::services::distance::distance_service::DistanceMatrix;
+use crate::solvers::branch_and_bound::branch_and_bound_solver::{
+    BranchAndBoundSolver, ExactSolveStatus,
+};
+use crate::solvers::genetic::genetic_solver::GeneticSolver;
+use crate::solvers::grasp::grasp_solver::GraspSolver;
+use crate::solvers::solution::Solution;
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::services_fixture::{route_service_factory, RouteServiceFactory};
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+fn solution_distance(distances: &DistanceMatrix, solution: &Solution) -> f64 {
+    solution
+        .result
+        .values()
+        .flat_map(|route| route.windows(2))
+        .map(|pair| distances.get(&(pair[0], pair[1])).copied().unwrap_or(0.0))
+        .sum()
+}
+
+#[rstest]
+fn exact_solution_is_optimal(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut solver =
+        BranchAndBoundSolver::new(vehicle_factory(2), &distances, stops, 10_000).unwrap();
+
+    solver.solve();
+
+    let solution_v1 = solver.solution.result.get(&0).unwrap();
+    let solution_v2 = solver.solution.result.get(&1).unwrap();
+
+    assert!(solver.is_optimal());
+    assert_eq!(solver.solution.total_distance, 8.0);
+    assert_eq!(solution_v1.first(), Some(&0));
+    assert_eq!(solution_v1.last(), Some(&0));
+    assert_eq!(solution_v2.first(), Some(&0));
+    assert_eq!(solution_v2.last(), Some(&0));
+}
+
+#[rstest]
+fn exact_solution_respects_capacity(distances: DistanceMatrix, vehicle_factory: VehicleFactory) {
+    let stops = Vec::from([
+        Stop::new(0, 0),
+        Stop::new(1, 4),
+        Stop::new(2, 4),
+        Stop::new(3, 4),
+        Stop::new(4, 4),
+    ]);
+
+    let mut solver =
+        BranchAndBoundSolver::new(vehicle_factory(2), &distances, stops, 10_000).unwrap();
+
+    solver.solve();
+
+    for route in solver.solution.result.values() {
+        assert!(route.len() <= 4);
+    }
+
+    assert!(solver.is_optimal());
+    assert_eq!(solver.solution.total_distance, 11.5);
+}
+
+#[rstest]
+fn exact_solver_reports_node_limit(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut solver = BranchAndBoundSolver::new(vehicle_factory(2), &distances, stops, 2).unwrap();
+
+    solver.solve();
+
+    assert!(!solver.is_optimal());
+}
+
+#[rstest]
+fn exact_solver_reports_infeasible_instances(
+    distances: DistanceMatrix,
+    vehicle_factory: VehicleFactory,
+) {
+    let stops = Vec::from([Stop::new(0, 0), Stop::new(1, 5), Stop::new(2, 5)]);
+
+    let mut solver =
+        BranchAndBoundSolver::new(vehicle_factory(1), &distances, stops, 10_000).unwrap();
+
+    assert_eq!(solver.solve(), ExactSolveStatus::Infeasible);
+    assert!(!solver.is_optimal());
+    assert!(solver.solution.result.is_empty());
+}
+
+#[rstest]
+fn exact_solver_reports_missing_depot(distances: DistanceMatrix, vehicle_factory: VehicleFactory) {
+    let stops = Vec::from([Stop::new(1, 1), Stop::new(2, 1)]);
+
+    let mut solver =
+        BranchAndBoundSolver::new(vehicle_factory(1), &distances, stops, 10_000).unwrap();
+
+    assert_eq!(solver.solve(), ExactSolveStatus::MissingDepot);
+    assert!(!solver.is_optimal());
+}
+
+#[rstest]
+fn exact_solver_rejects_large_instances(
+    distances: DistanceMatrix,
+    vehicle_factory: VehicleFactory,
+) {
+    let stops: Vec<Stop> = (0..30).map(|id| Stop::new(id, 0)).collect();
+
+    assert!(BranchAndBoundSolver::new(vehicle_factory(2), &distances, stops, 10).is_err());
+}
+
+#[rstest]
+fn heuristics_gap_to_optimal(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+    route_service_factory: RouteServiceFactory,
+) {
+    let mut exact =
+        BranchAndBoundSolver::new(vehicle_factory(2), &distances, stops.clone(), 10_000).unwrap();
+    exact.solve();
+
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let mut grasp = GraspSolver::new(
+        3,
+        vehicle_factory(2),
+        &distances,
+        3,
+        stops.clone(),
+        &mut rng,
+    );
+    grasp.solve();
+
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let mut genetic = GeneticSolver::new(
+        stops,
+        &distances,
+        10,
+        3,
+        0.05,
+        10,
+        5,
+        route_service_factory(2),
+        &mut rng,
+    );
+    genetic.solve();
+
+    let optimal = exact.solution.total_distance;
+    let grasp_distance = solution_distance(&distances, &grasp.solution);
+    let genetic_distance = solution_distance(&distances, &genetic.solution);
+
+    assert_eq!(grasp_distance, grasp.solution.total_distance);
+    assert!(grasp_distance >= optimal);
+    assert!(genetic_distance >= optimal);
+    assert_eq!((grasp_distance - optimal) / optimal, 0.0);
+}
@@ -0,0 +1,2 @@
+#[cfg(test)]
+pub mod branch_and_bound_solver_test;
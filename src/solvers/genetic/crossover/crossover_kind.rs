@@ -0,0 +1,7 @@
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CrossoverKind {
+    Order,
+    PartiallyMapped,
+    EdgeRecombination,
+    SelectiveRouteExchange,
+}
@@ -0,0 +1,118 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        giant_tour::{align_tours, GiantTour},
+        individual::{Gene, Individual},
+        split::Split,
+    },
+};
+
+use super::crossover_operator::CrossoverOperator;
+
+type EdgeMap = HashMap<Gene, HashSet<Gene>>;
+
+#[derive(Clone)]
+pub(crate) struct EdgeRecombinationCrossover {
+    max_of_tries: u8,
+    split: Arc<Split>,
+}
+
+impl CrossoverOperator<EdgeRecombinationCrossover> for EdgeRecombinationCrossover {
+    fn run<R>(
+        &self,
+        parent1: Individual,
+        parent2: Individual,
+        rng: &mut R,
+        _distance_service: &DistanceService,
+    ) -> Option<Individual>
+    where
+        R: Rng + ?Sized,
+    {
+        let tour1 = parent1.giant_tour();
+        let tour2 = align_tours(&tour1, &parent2.giant_tour());
+
+        let offspring = Self::cross(&tour1, &tour2, rng);
+
+        Individual::from_giant_tour(&offspring, &self.split)
+    }
+
+    fn max_of_tries(&self) -> u8 {
+        self.max_of_tries
+    }
+}
+
+impl EdgeRecombinationCrossover {
+    pub(crate) fn new(max_of_tries: u8, split: Arc<Split>) -> Self {
+        Self {
+            max_of_tries,
+            split,
+        }
+    }
+
+    fn add_edges(edges: &mut EdgeMap, tour: &[Gene]) {
+        for (index, gene) in tour.iter().enumerate() {
+            let neighbours = edges.entry(*gene).or_default();
+
+            if index > 0 {
+                neighbours.insert(tour[index - 1]);
+            }
+
+            if index + 1 < tour.len() {
+                neighbours.insert(tour[index + 1]);
+            }
+        }
+    }
+
+    pub(crate) fn cross<R>(tour1: &[Gene], tour2: &[Gene], rng: &mut R) -> GiantTour
+    where
+        R: Rng + ?Sized,
+    {
+        let mut edges = EdgeMap::new();
+
+        Self::add_edges(&mut edges, tour1);
+        Self::add_edges(&mut edges, tour2);
+
+        let mut offspring = GiantTour::with_capacity(tour1.len());
+        let mut current = match tour1.first() {
+            Some(gene) => *gene,
+            None => return offspring,
+        };
+
+        loop {
+            offspring.push(current);
+
+            let neighbours = edges.remove(&current).unwrap_or_default();
+
+            for remaining in edges.values_mut() {
+                remaining.remove(&current);
+            }
+
+            let fewest_edges = neighbours
+                .iter()
+                .filter_map(|gene| edges.get(gene).map(|edges| edges.len()))
+                .min();
+
+            let mut candidates: Vec<Gene> = match fewest_edges {
+                Some(fewest_edges) => neighbours
+                    .into_iter()
+                    .filter(|gene| edges.get(gene).map(|edges| edges.len()) == Some(fewest_edges))
+                    .collect(),
+                None => edges.keys().copied().collect(),
+            };
+
+            candidates.sort_by_key(|gene| gene.id);
+
+            current = match candidates.choose(rng) {
+                Some(gene) => *gene,
+                None => return offspring,
+            };
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{individual::Individual, split::Split},
+};
+
+use super::{
+    crossover_kind::CrossoverKind, crossover_operator::CrossoverOperator,
+    edge_recombination_crossover::EdgeRecombinationCrossover, order_crossover::OrderCrossover,
+    partially_mapped_crossover::PartiallyMappedCrossover,
+    selective_route_exchange_crossover::SelectiveRouteExchangeCrossover,
+};
+
+#[derive(Clone)]
+pub(crate) struct MixedCrossover {
+    max_of_tries: u8,
+    weights: Vec<(CrossoverKind, f64)>,
+    order: OrderCrossover,
+    partially_mapped: PartiallyMappedCrossover,
+    edge_recombination: EdgeRecombinationCrossover,
+    selective_route_exchange: SelectiveRouteExchangeCrossover,
+}
+
+impl CrossoverOperator<MixedCrossover> for MixedCrossover {
+    fn run<R>(
+        &self,
+        parent1: Individual,
+        parent2: Individual,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> Option<Individual>
+    where
+        R: Rng + ?Sized,
+    {
+        let (kind, _) = self
+            .weights
+            .choose_weighted(rng, |(_, weight)| *weight)
+            .ok()?;
+
+        match kind {
+            CrossoverKind::Order => self.order.run(parent1, parent2, rng, distance_service),
+            CrossoverKind::PartiallyMapped => {
+                self.partially_mapped
+                    .run(parent1, parent2, rng, distance_service)
+            }
+            CrossoverKind::EdgeRecombination => {
+                self.edge_recombination
+                    .run(parent1, parent2, rng, distance_service)
+            }
+            CrossoverKind::SelectiveRouteExchange => {
+                self.selective_route_exchange
+                    .run(parent1, parent2, rng, distance_service)
+            }
+        }
+    }
+
+    fn max_of_tries(&self) -> u8 {
+        self.max_of_tries
+    }
+}
+
+impl MixedCrossover {
+    pub(crate) fn new(
+        max_of_tries: u8,
+        split: Arc<Split>,
+        weights: Vec<(CrossoverKind, f64)>,
+    ) -> Self {
+        Self {
+            weights,
+            max_of_tries,
+            order: OrderCrossover::new(max_of_tries),
+            partially_mapped: PartiallyMappedCrossover::new(max_of_tries, split.clone()),
+            edge_recombination: EdgeRecombinationCrossover::new(max_of_tries, split.clone()),
+            selective_route_exchange: SelectiveRouteExchangeCrossover::new(max_of_tries, split),
+        }
+    }
+
+    pub(crate) fn set_weights(&mut self, weights: Vec<(CrossoverKind, f64)>) {
+        self.weights = weights;
+    }
+}
@@ -1,6 +1,11 @@
+pub mod crossover_kind;
 pub(crate) mod crossover_operator;
+pub(crate) mod edge_recombination_crossover;
 mod individual;
+pub(crate) mod mixed_crossover;
 pub(crate) mod offspring;
 pub(crate) mod order_crossover;
 mod parent_slice;
+pub(crate) mod partially_mapped_crossover;
+pub(crate) mod selective_route_exchange_crossover;
 mod tests;
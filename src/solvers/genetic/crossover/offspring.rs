@@ -38,6 +38,7 @@ impl<T: CrossoverOperator<T> + Clone> Offspring<T> {
                 rng,
                 distance_service,
             )?;
+            self.individual.set_penalty(self.parent1.penalty);
 
             if self.has_evolved() {
                 return Some(());
@@ -79,9 +79,7 @@ impl ParentSlice {
     ) -> Option<Chromosome> {
         let mut offspring_chromosome = Chromosome::new(chromosome.vehicle);
 
-        offspring_chromosome
-            .add_stop(chromosome.stops[0], 0.0)
-            .unwrap();
+        offspring_chromosome.force_add_stop(chromosome.stops[0], 0.0);
 
         let unrepeated_genes: Vec<Gene> =
             Individual::drop_gene_duplicates(&chromosome, &self.gene_set);
@@ -100,8 +98,7 @@ impl ParentSlice {
                         .unwrap(),
                 )
             })
-            .try_for_each(|(gene, distance)| offspring_chromosome.add_stop(gene, distance))
-            .ok()?;
+            .for_each(|(gene, distance)| offspring_chromosome.force_add_stop(gene, distance));
 
         Some(offspring_chromosome)
     }
@@ -0,0 +1,82 @@
+use std::{collections::HashMap, sync::Arc};
+
+use rand::Rng;
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        giant_tour::{align_tours, generate_cut, GiantTour},
+        individual::{Gene, Individual},
+        split::Split,
+    },
+};
+
+use super::crossover_operator::CrossoverOperator;
+
+#[derive(Clone)]
+pub(crate) struct PartiallyMappedCrossover {
+    max_of_tries: u8,
+    split: Arc<Split>,
+}
+
+impl CrossoverOperator<PartiallyMappedCrossover> for PartiallyMappedCrossover {
+    fn run<R>(
+        &self,
+        parent1: Individual,
+        parent2: Individual,
+        rng: &mut R,
+        _distance_service: &DistanceService,
+    ) -> Option<Individual>
+    where
+        R: Rng + ?Sized,
+    {
+        let tour1 = parent1.giant_tour();
+        let tour2 = align_tours(&tour1, &parent2.giant_tour());
+
+        let offspring = Self::cross(&tour1, &tour2, rng);
+
+        Individual::from_giant_tour(&offspring, &self.split)
+    }
+
+    fn max_of_tries(&self) -> u8 {
+        self.max_of_tries
+    }
+}
+
+impl PartiallyMappedCrossover {
+    pub(crate) fn new(max_of_tries: u8, split: Arc<Split>) -> Self {
+        Self {
+            max_of_tries,
+            split,
+        }
+    }
+
+    pub(crate) fn cross<R>(tour1: &[Gene], tour2: &[Gene], rng: &mut R) -> GiantTour
+    where
+        R: Rng + ?Sized,
+    {
+        let (start, end) = generate_cut(tour1.len(), rng);
+
+        let mapping: HashMap<u32, Gene> = (start..end)
+            .map(|index| (tour1[index].id, tour2[index]))
+            .collect();
+
+        tour2
+            .iter()
+            .enumerate()
+            .map(|(index, gene)| {
+                if (start..end).contains(&index) {
+                    return tour1[index];
+                }
+
+                let mut gene = *gene;
+
+                while let Some(mapped) = mapping.get(&gene.id) {
+                    gene = *mapped;
+                }
+
+                gene
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,159 @@
+use std::{collections::HashSet, sync::Arc};
+
+use rand::{seq::IteratorRandom, Rng};
+
+use crate::{
+    domain::vehicle::Vehicle,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        individual::{Chromosome, Gene, Individual},
+        split::Split,
+    },
+};
+
+use super::crossover_operator::CrossoverOperator;
+
+#[derive(Clone)]
+pub(crate) struct SelectiveRouteExchangeCrossover {
+    max_of_tries: u8,
+    split: Arc<Split>,
+}
+
+impl CrossoverOperator<SelectiveRouteExchangeCrossover> for SelectiveRouteExchangeCrossover {
+    fn run<R>(
+        &self,
+        parent1: Individual,
+        parent2: Individual,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> Option<Individual>
+    where
+        R: Rng + ?Sized,
+    {
+        let amount = rng.gen_range(1..=Self::customer_routes(&parent1).count().max(1));
+        let selected: Vec<&Chromosome> =
+            Self::customer_routes(&parent1).choose_multiple(rng, amount);
+
+        let selected_genes: HashSet<Gene> = selected
+            .iter()
+            .flat_map(|chromosome| chromosome.customers())
+            .collect();
+
+        let mut routes: Vec<(Vehicle, Vec<Gene>)> = parent2
+            .chromosomes
+            .iter()
+            .map(|chromosome| {
+                let vehicle = Vehicle::new(chromosome.vehicle.id, chromosome.vehicle.capacity);
+
+                match selected.iter().find(|route| route.vehicle.id == vehicle.id) {
+                    Some(route) => (vehicle, route.customers()),
+                    None => (
+                        vehicle,
+                        chromosome
+                            .customers()
+                            .into_iter()
+                            .filter(|gene| !selected_genes.contains(gene))
+                            .collect(),
+                    ),
+                }
+            })
+            .collect();
+
+        let present: HashSet<Gene> = routes
+            .iter()
+            .flat_map(|(_, genes)| genes.iter().copied())
+            .collect();
+
+        let missing: Vec<Gene> = parent1
+            .giant_tour()
+            .into_iter()
+            .filter(|gene| !present.contains(gene))
+            .collect();
+
+        for gene in missing {
+            self.insert_gene(&mut routes, gene, distance_service)?;
+        }
+
+        let chromosomes = routes
+            .iter()
+            .map(|(vehicle, genes)| self.split.build_route(*vehicle, genes))
+            .collect::<Option<Vec<Chromosome>>>()?;
+
+        Some(Individual::new(chromosomes))
+    }
+
+    fn max_of_tries(&self) -> u8 {
+        self.max_of_tries
+    }
+}
+
+impl SelectiveRouteExchangeCrossover {
+    pub(crate) fn new(max_of_tries: u8, split: Arc<Split>) -> Self {
+        Self {
+            max_of_tries,
+            split,
+        }
+    }
+
+    fn customer_routes(individual: &Individual) -> impl Iterator<Item = &Chromosome> {
+        individual
+            .chromosomes
+            .iter()
+            .filter(|chromosome| chromosome.stops.len() > 2)
+    }
+
+    fn insertion_cost(
+        &self,
+        genes: &[Gene],
+        index: usize,
+        gene: &Gene,
+        distance_service: &DistanceService,
+    ) -> Option<f64> {
+        let depot = self.split.depot.as_ref()?;
+        let previous = match index {
+            0 => depot,
+            _ => &genes[index - 1],
+        };
+
+        let next = genes.get(index).unwrap_or(depot);
+
+        Some(
+            distance_service.get_distance(previous, gene)?
+                + distance_service.get_distance(gene, next)?
+                - distance_service.get_distance(previous, next)?,
+        )
+    }
+
+    fn insert_gene(
+        &self,
+        routes: &mut [(Vehicle, Vec<Gene>)],
+        gene: Gene,
+        distance_service: &DistanceService,
+    ) -> Option<()> {
+        let mut best: Option<(f64, usize, usize)> = None;
+
+        for (route_index, (vehicle, genes)) in routes.iter().enumerate() {
+            let load: u32 = genes.iter().map(|gene| gene.usage).sum();
+
+            if load + gene.usage > self.split.capacity(vehicle) {
+                continue;
+            }
+
+            for index in 0..=genes.len() {
+                let cost = match self.insertion_cost(genes, index, &gene, distance_service) {
+                    Some(cost) => cost,
+                    None => continue,
+                };
+
+                if best.map_or(true, |(best_cost, _, _)| cost < best_cost) {
+                    best = Some((cost, route_index, index));
+                }
+            }
+        }
+
+        let (_, route_index, index) = best?;
+        routes[route_index].1.insert(index, gene);
+
+        Some(())
+    }
+}
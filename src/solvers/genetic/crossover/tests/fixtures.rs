@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use rstest::fixture;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceMatrix,
+    solvers::genetic::{individual::Individual, split::Split},
+    tests::fixtures::{
+        distances_fixture::distances,
+        stops_fixture::stops,
+        vehicles_fixture::{vehicle_factory, VehicleFactory},
+    },
+};
+
+pub(crate) type RouteParents = (Individual, Individual);
+
+#[fixture]
+pub(crate) fn split(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) -> Arc<Split> {
+    Arc::new(Split::new(vehicle_factory(2), &distances, stops))
+}
+
+fn individual(split: &Split, stops: &[Stop], routes: [&[usize]; 2]) -> Individual {
+    let chromosomes = routes
+        .iter()
+        .zip(vehicle_factory()(2))
+        .map(|(route, vehicle)| {
+            let genes: Vec<Stop> = route.iter().map(|index| stops[*index]).collect();
+            split.build_route(vehicle, &genes).unwrap()
+        })
+        .collect();
+
+    Individual::new(chromosomes)
+}
+
+#[fixture]
+pub(crate) fn route_parents(split: Arc<Split>, stops: Vec<Stop>) -> RouteParents {
+    let parent1 = individual(&split, &stops, [&[1, 2], &[3]]);
+    let parent2 = individual(&split, &stops, [&[3, 1], &[2]]);
+
+    (parent1, parent2)
+}
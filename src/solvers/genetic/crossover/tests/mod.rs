@@ -1 +1,13 @@
+#[cfg(test)]
+mod edge_recombination_crossover_test;
+
+#[cfg(test)]
+mod fixtures;
+
 mod order_crossover;
+
+#[cfg(test)]
+mod partially_mapped_crossover_test;
+
+#[cfg(test)]
+mod selective_route_exchange_crossover_test;
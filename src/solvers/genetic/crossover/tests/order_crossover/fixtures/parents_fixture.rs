@@ -13,8 +13,8 @@ pub(crate) type Parents = (Individual, Individual);
 
 #[fixture]
 pub(crate) fn parents(stops: Vec<Stop>, route_factory: RouteFactory) -> Parents {
-    let route1 = route_factory(vec![stops[0], stops[1], stops[0]].to_vec());
-    let route2 = route_factory(vec![stops[0], stops[2], stops[3], stops[0]].to_vec());
+    let route1 = route_factory([stops[0], stops[1], stops[0]].to_vec());
+    let route2 = route_factory([stops[0], stops[2], stops[3], stops[0]].to_vec());
 
     let parent1 = Individual::new(vec![route1, route2]);
     let mut parent2 = parent1.clone();
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::domain::stop::Stop;
+use crate::services::distance::distance_service::DistanceService;
+use crate::solvers::genetic::crossover::crossover_operator::CrossoverOperator;
+use crate::solvers::genetic::crossover::partially_mapped_crossover::PartiallyMappedCrossover;
+use crate::solvers::genetic::split::Split;
+use crate::tests::fixtures::services_fixture::distance_service;
+
+use super::fixtures::{route_parents, split, RouteParents};
+
+#[rstest]
+fn partially_mapped_crossover_keeps_permutation() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let tour1: Vec<Stop> = (1..9).map(|id| Stop::new(id, 0)).collect();
+    let tour2: Vec<Stop> = [3, 7, 5, 1, 6, 8, 2, 4]
+        .iter()
+        .map(|id| Stop::new(*id, 0))
+        .collect();
+
+    for _ in 0..20 {
+        let mut offspring: Vec<u32> = PartiallyMappedCrossover::cross(&tour1, &tour2, &mut rng)
+            .iter()
+            .map(|stop| stop.id)
+            .collect();
+
+        offspring.sort();
+
+        assert_eq!(offspring, (1..9).collect::<Vec<u32>>());
+    }
+}
+
+#[rstest]
+fn partially_mapped_crossover_generates_offspring(
+    split: Arc<Split>,
+    route_parents: RouteParents,
+    distance_service: DistanceService,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let crossover_op = PartiallyMappedCrossover::new(10, split);
+
+    let offspring = crossover_op
+        .run(
+            route_parents.0,
+            route_parents.1,
+            &mut rng,
+            &distance_service,
+        )
+        .unwrap();
+
+    let mut genes: Vec<u32> = offspring.giant_tour().iter().map(|stop| stop.id).collect();
+    genes.sort();
+
+    assert_eq!(genes, vec![1, 2, 3]);
+    assert_eq!(offspring.chromosomes.len(), 2);
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::services::distance::distance_service::DistanceService;
+use crate::solvers::genetic::crossover::crossover_operator::CrossoverOperator;
+use crate::solvers::genetic::crossover::selective_route_exchange_crossover::SelectiveRouteExchangeCrossover;
+use crate::solvers::genetic::split::Split;
+use crate::tests::fixtures::services_fixture::distance_service;
+
+use super::fixtures::{route_parents, split, RouteParents};
+
+#[rstest]
+fn selective_route_exchange_keeps_every_gene_once(
+    split: Arc<Split>,
+    route_parents: RouteParents,
+    distance_service: DistanceService,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let crossover_op = SelectiveRouteExchangeCrossover::new(10, split);
+
+    for _ in 0..20 {
+        let offspring = crossover_op
+            .run(
+                route_parents.0.clone(),
+                route_parents.1.clone(),
+                &mut rng,
+                &distance_service,
+            )
+            .unwrap();
+
+        let mut genes: Vec<u32> = offspring.giant_tour().iter().map(|stop| stop.id).collect();
+        genes.sort();
+
+        assert_eq!(genes, vec![1, 2, 3]);
+        assert_eq!(offspring.chromosomes.len(), 2);
+
+        for chromosome in offspring.chromosomes.iter() {
+            assert_eq!(chromosome.stops.first().unwrap().id, 0);
+            assert_eq!(chromosome.stops.last().unwrap().id, 0);
+        }
+    }
+}
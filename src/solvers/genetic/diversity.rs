@@ -0,0 +1,36 @@
+use std::collections::HashSet;
+
+use super::individual::Individual;
+
+pub(crate) type Edge = (u32, u32);
+
+impl Individual {
+    pub(crate) fn edges(&self) -> HashSet<Edge> {
+        self.chromosomes
+            .iter()
+            .flat_map(|chromosome| chromosome.stops.windows(2))
+            .filter(|window| window[0].id != window[1].id)
+            .map(|window| match window[0].id < window[1].id {
+                true => (window[0].id, window[1].id),
+                false => (window[1].id, window[0].id),
+            })
+            .collect()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn broken_pairs_distance(&self, other: &Individual) -> f64 {
+        edge_distance(&self.edges(), &other.edges())
+    }
+}
+
+pub(crate) fn edge_distance(edges: &HashSet<Edge>, other_edges: &HashSet<Edge>) -> f64 {
+    let size = edges.len().max(other_edges.len());
+
+    if size == 0 {
+        return 0.0;
+    }
+
+    let shared = edges.intersection(other_edges).count();
+
+    1.0 - shared as f64 / size as f64
+}
@@ -0,0 +1,91 @@
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    local_search::{
+        improvement_strategy::ImprovementStrategy,
+        inter_route::inter_route_searcher::InterRouteSearcher, or_opt::OrOptSearcher,
+        two_opt::TwoOptSearcher,
+    },
+    services::distance::{distance_oracle::IntoDistanceOracle, distance_service::DistanceService},
+};
+
+use super::individual::{Chromosome, Individual};
+
+const INTER_ROUTE_SEGMENT_LENGTH: usize = 3;
+
+pub(crate) struct Education {
+    pub(crate) probability: f64,
+    distance_service: DistanceService,
+    two_opt: TwoOptSearcher,
+    or_opt: OrOptSearcher,
+    inter_route: InterRouteSearcher,
+}
+
+impl Education {
+    pub(crate) fn new(
+        stops: Vec<Stop>,
+        distances: impl IntoDistanceOracle,
+        probability: f64,
+    ) -> Self {
+        let oracle = distances.into_oracle();
+
+        Self {
+            probability,
+            distance_service: DistanceService::new(stops.clone(), &oracle),
+            two_opt: TwoOptSearcher::new(
+                stops.clone(),
+                &oracle,
+                ImprovementStrategy::BestImprovement,
+                None,
+            ),
+            or_opt: OrOptSearcher::new(
+                stops.clone(),
+                &oracle,
+                ImprovementStrategy::BestImprovement,
+                None,
+            ),
+            inter_route: InterRouteSearcher::new(stops, &oracle, INTER_ROUTE_SEGMENT_LENGTH, None),
+        }
+    }
+
+    pub(crate) fn set_probability(&mut self, probability: f64) {
+        self.probability = probability.clamp(0.0, 1.0);
+    }
+
+    fn rebuild(&self, chromosome: &Chromosome) -> Option<Chromosome> {
+        let vehicle = Vehicle::new(chromosome.vehicle.id, chromosome.vehicle.capacity);
+        let mut route = Route::new(vehicle);
+
+        for (index, stop) in chromosome.stops.iter().enumerate() {
+            let distance = match index {
+                0 => 0.0,
+                _ => self
+                    .distance_service
+                    .get_distance(&chromosome.stops[index - 1], stop)?,
+            };
+
+            route.add_stop(*stop, distance).ok()?;
+        }
+
+        Some(route)
+    }
+
+    pub(crate) fn educate(&self, individual: &mut Individual) -> Option<()> {
+        let mut chromosomes = individual
+            .chromosomes
+            .iter()
+            .map(|chromosome| self.rebuild(chromosome))
+            .collect::<Option<Vec<Chromosome>>>()?;
+
+        for chromosome in chromosomes.iter_mut() {
+            self.two_opt.run(chromosome);
+            self.or_opt.run(chromosome);
+        }
+
+        self.inter_route.run(&mut chromosomes);
+
+        individual.chromosomes = chromosomes;
+        individual.update_fitness();
+
+        Some(())
+    }
+}
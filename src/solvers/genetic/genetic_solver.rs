@@ -1,9 +1,12 @@
-use rand::{seq::SliceRandom, thread_rng, Rng};
+use std::sync::Arc;
+
+use rand::Rng;
 
 use crate::{
-    domain::stop::Stop,
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    errors::solver::invalid_solution::InvalidSolutionError,
     services::{
-        distance::distance_service::DistanceMatrix,
+        distance::distance_oracle::IntoDistanceOracle,
         route::route_service::{RouteMap, RouteService},
     },
     solvers::solution::Solution,
@@ -11,21 +14,43 @@ use crate::{
 };
 
 use super::{
-    crossover::{offspring::Offspring, order_crossover::OrderCrossover},
+    crossover::{
+        crossover_kind::CrossoverKind, crossover_operator::CrossoverOperator,
+        mixed_crossover::MixedCrossover, offspring::Offspring,
+    },
+    education::Education,
     individual::Individual,
+    mutation::{Mutation, MutationKind, MutationSchedule},
+    penalty::Penalty,
     population::Population,
+    repair::Repair,
+    selection::SelectionStrategy,
+    split::Split,
+    stopping_criteria::StoppingCriteria,
 };
 
 pub struct GeneticSolver<'a, R: Rng + ?Sized> {
     elite_size: usize,
-    mutation_rate: f64,
+    population_size: usize,
+    max_population_size: usize,
+    mutation: Mutation,
     population: Population,
+    infeasible_population: Population,
+    allow_infeasible: bool,
+    penalty: Penalty,
+    repair: Repair,
     stop_swapper: StopSwapper,
     max_generations: u32,
     current_generation: u32,
+    generations_without_improvement: u32,
+    stopping_criteria: StoppingCriteria,
+    restart_after: Option<u32>,
+    route_service: RouteService,
     pub solution: Solution,
     best: Individual,
-    crossover_op: OrderCrossover,
+    crossover_op: MixedCrossover,
+    selection: SelectionStrategy,
+    education: Education,
     rng: &'a mut R,
 }
 
@@ -33,7 +58,7 @@ impl<'a, R: Rng + ?Sized> GeneticSolver<'a, R> {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         stops: Vec<Stop>,
-        distances: &DistanceMatrix,
+        distances: impl IntoDistanceOracle,
         population_size: u32,
         elite_size: usize,
         mutation_rate: f64,
@@ -42,47 +67,188 @@ impl<'a, R: Rng + ?Sized> GeneticSolver<'a, R> {
         mut route_service: RouteService,
         rng: &'a mut R,
     ) -> Self {
-        let stop_swapper = StopSwapper::new(stops, distances);
-        let crossover_op = OrderCrossover::new(max_crossover_tries);
+        let oracle = distances.into_oracle();
+
+        let vehicles: Vec<Vehicle> = route_service
+            .get_vehicles()
+            .iter()
+            .map(|vehicle| Vehicle::new(vehicle.id, vehicle.capacity))
+            .collect();
+
+        let split = Arc::new(Split::new(vehicles, &oracle, stops.clone()));
+        let education = Education::new(stops.clone(), &oracle, 0.0);
+        let stop_swapper = StopSwapper::new(stops, &oracle);
+        let crossover_op = MixedCrossover::new(
+            max_crossover_tries,
+            split.clone(),
+            vec![(CrossoverKind::Order, 1.0)],
+        );
+        let mutation = Mutation::new(
+            mutation_rate,
+            split.clone(),
+            vec![(MutationKind::Swap, 1.0)],
+        );
+        let repair = Repair::new(split);
         let population = Population::from_random(population_size, rng, &mut route_service);
 
         Self {
             rng,
             elite_size,
             population,
+            infeasible_population: Default::default(),
+            allow_infeasible: false,
+            penalty: Default::default(),
+            repair,
+            population_size: population_size as usize,
+            max_population_size: 2 * population_size as usize,
+            education,
             crossover_op,
             stop_swapper,
-            mutation_rate,
+            selection: Default::default(),
+            mutation,
             max_generations,
             best: Default::default(),
             solution: Default::default(),
             current_generation: Default::default(),
+            generations_without_improvement: Default::default(),
+            stopping_criteria: Default::default(),
+            restart_after: None,
+            route_service,
         }
     }
 
-    pub(super) fn selection(&self) -> Vec<(usize, Individual)> {
+    pub fn with_crossovers(mut self, crossovers: Vec<(CrossoverKind, f64)>) -> Self {
+        self.crossover_op.set_weights(crossovers);
+        self
+    }
+
+    pub fn with_mutations(mut self, mutations: Vec<(MutationKind, f64)>) -> Self {
+        self.mutation.set_weights(mutations);
+        self
+    }
+
+    pub fn with_mutation_schedule(mut self, schedule: MutationSchedule) -> Self {
+        self.mutation.set_schedule(schedule);
+        self
+    }
+
+    pub fn with_infeasible_individuals(mut self, initial_penalty: f64) -> Self {
+        self.allow_infeasible = true;
+        self.penalty = Penalty::new(initial_penalty);
+        self.update_penalties();
+        self
+    }
+
+    fn update_penalties(&mut self) {
+        let penalty = self.penalty.value;
+
         self.population
-            .get_k_bests(self.elite_size)
-            .choose_multiple_weighted(&mut thread_rng(), 2, |individual| individual.fitness)
-            .unwrap()
+            .individuals
+            .iter_mut()
+            .chain(self.infeasible_population.individuals.iter_mut())
+            .for_each(|individual| individual.set_penalty(penalty));
+    }
+
+    pub fn with_initial_solution(
+        mut self,
+        solution: &Solution,
+    ) -> Result<Self, InvalidSolutionError> {
+        self.route_service.load_solution(solution)?;
+
+        let routes: Vec<Route> = self
+            .route_service
+            .get_all_routes()
+            .values()
             .cloned()
-            .enumerate()
-            .collect()
+            .collect();
+        self.route_service.reset();
+
+        let mut individual = Individual::new(routes);
+        individual.set_penalty(self.penalty.value);
+
+        if self.should_update_best(&individual) {
+            self.best = individual.clone();
+        }
+
+        self.population.add(individual);
+        self.population
+            .select_survivors(self.population_size, self.elite_size);
+
+        Ok(self)
     }
 
-    pub(super) fn mutation(&mut self) {
-        let stop_swapper = &self.stop_swapper;
+    pub fn with_stopping_criteria(mut self, stopping_criteria: StoppingCriteria) -> Self {
+        self.stopping_criteria = stopping_criteria;
+        self
+    }
 
-        let mutated_individuals: Vec<&mut Individual> = self
+    pub fn with_restart(mut self, stagnation_generations: u32) -> Self {
+        self.restart_after = Some(stagnation_generations).filter(|generations| *generations > 0);
+        self
+    }
+
+    pub fn with_max_population_size(mut self, max_population_size: usize) -> Self {
+        self.max_population_size = max_population_size.max(self.population_size);
+        self
+    }
+
+    pub fn with_education(mut self, probability: f64) -> Self {
+        self.education.set_probability(probability);
+        self
+    }
+
+    pub(super) fn educate(&mut self, individual: &mut Individual) {
+        if self.rng.gen_bool(self.education.probability) {
+            self.education.educate(individual);
+        }
+    }
+
+    pub fn with_selection(mut self, selection: SelectionStrategy) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    pub(super) fn selection(&mut self) -> Option<Vec<(usize, Individual)>> {
+        let candidates: Vec<&Individual> = self
             .population
             .individuals
-            .iter_mut()
-            .filter(|_| self.rng.gen_bool(self.mutation_rate))
+            .iter()
+            .chain(self.infeasible_population.individuals.iter())
             .collect();
 
-        for individual in mutated_individuals {
-            individual.swap_random_genes(stop_swapper, self.rng);
+        let (parent1, parent2) =
+            self.selection
+                .select_pair(&candidates, self.rng, self.crossover_op.max_of_tries())?;
+
+        Some(vec![
+            (parent1, candidates[parent1].clone()),
+            (parent2, candidates[parent2].clone()),
+        ])
+    }
+
+    pub(super) fn mutation(&mut self) {
+        let elites = self.population.get_k_bests(self.elite_size);
+
+        for index in 0..self.population.individuals.len() {
+            if elites.contains(&index) || !self.rng.gen_bool(self.mutation.rate) {
+                continue;
+            }
+
+            let individual = &mut self.population.individuals[index];
+
+            if !self.mutation.mutate(individual, self.rng) {
+                continue;
+            }
+
+            if individual.fitness < self.best.fitness {
+                self.best = individual.clone();
+            }
+
+            self.population.refresh(index);
         }
+
+        self.mutation
+            .update(self.current_generation + 1, self.max_generations);
     }
 
     pub(super) fn crossover(
@@ -102,42 +268,194 @@ impl<'a, R: Rng + ?Sized> GeneticSolver<'a, R> {
     }
 
     fn stop_condition_met(&self) -> bool {
-        self.current_generation >= self.max_generations
+        if self.current_generation >= self.max_generations {
+            return true;
+        }
+
+        self.stopping_criteria.is_met(
+            self.generations_without_improvement,
+            self.best.fitness,
+            || self.population.diversity(),
+        )
+    }
+
+    pub fn generations(&self) -> u32 {
+        self.current_generation
+    }
+
+    fn should_restart(&self) -> bool {
+        self.restart_after.is_some_and(|generations| {
+            self.generations_without_improvement > 0
+                && self.generations_without_improvement % generations == 0
+        })
+    }
+
+    fn restart(&mut self) {
+        let elites: Vec<Individual> = self
+            .population
+            .get_k_bests(self.elite_size)
+            .into_iter()
+            .map(|index| self.population.individuals[index].clone())
+            .collect();
+
+        let size = self.population_size.saturating_sub(elites.len()) as u32;
+        self.route_service.reset();
+
+        self.population = Population::from_random(size, self.rng, &mut self.route_service);
+        self.infeasible_population = Default::default();
+
+        for elite in elites {
+            self.population.add(elite);
+        }
+
+        self.update_penalties();
     }
 
     fn should_update_best(&self, individual: &Individual) -> bool {
         individual.fitness < self.best.fitness
     }
 
-    pub fn solve(&mut self) {
-        while !self.stop_condition_met() {
-            loop {
-                let parents = self.selection();
+    fn run_generation(&mut self) {
+        let previous_best = self.best.fitness;
+
+        while let Some(parents) = self.selection() {
+            let (_, parent1) = &parents[0];
+            let (_, parent2) = &parents[1];
+
+            let (mut offspring1, mut offspring2) = match self.crossover(parent1, parent2) {
+                Some(offsprings) => offsprings,
+                None => break,
+            };
+
+            self.educate(&mut offspring1);
+            self.educate(&mut offspring2);
+
+            let added1 = self.add_offspring(offspring1);
+            let added2 = self.add_offspring(offspring2);
+
+            if !added1 && !added2 {
+                break;
+            }
+        }
+
+        self.mutation();
+        self.update_best_from_population();
+
+        if self.allow_infeasible && self.penalty.adapt() {
+            self.update_penalties();
+        }
+
+        self.generations_without_improvement = match self.best.fitness < previous_best {
+            true => 0,
+            false => self.generations_without_improvement + 1,
+        };
+
+        if self.should_restart() {
+            self.restart();
+        }
+
+        self.current_generation += 1;
+    }
+
+    fn add_feasible(&mut self, individual: Individual) -> bool {
+        if self.should_update_best(&individual) {
+            self.best = individual.clone();
+        }
+
+        let added = self.population.add(individual);
+
+        if self.population.individuals.len() > self.max_population_size {
+            self.population
+                .select_survivors(self.population_size, self.elite_size);
+        }
+
+        added
+    }
 
-                let (parent1_index, parent1) = &parents[0];
-                let (parent2_index, parent2) = &parents[1];
+    fn add_infeasible(&mut self, individual: Individual) -> bool {
+        let added = self.infeasible_population.add(individual);
 
-                let (offspring1, offspring2) = match self.crossover(parent1, parent2) {
-                    Some(offsprings) => offsprings,
-                    None => break,
-                };
+        if self.infeasible_population.individuals.len() > self.max_population_size {
+            self.infeasible_population
+                .select_survivors(self.population_size, 0);
+        }
+
+        added
+    }
 
-                if self.should_update_best(&offspring1) {
-                    self.best = offspring1.clone();
-                }
+    fn add_offspring(&mut self, mut offspring: Individual) -> bool {
+        offspring.set_penalty(self.penalty.value);
+        self.penalty.record(offspring.is_feasible());
+
+        if offspring.is_feasible() {
+            return self.add_feasible(offspring);
+        }
 
-                if self.should_update_best(&offspring2) {
-                    self.best = offspring2.clone();
-                }
+        let repaired = self.repair.repair(&offspring);
+
+        let added = match self.allow_infeasible {
+            true => self.add_infeasible(offspring),
+            false => false,
+        };
+
+        match repaired {
+            Some(repaired) => self.add_feasible(repaired) || added,
+            None => added,
+        }
+    }
 
-                self.population.individuals[*parent1_index] = offspring1;
-                self.population.individuals[*parent2_index] = offspring2;
+    fn update_best_from_population(&mut self) {
+        if let Some(best) = self.population.best() {
+            if self.should_update_best(best) {
+                self.best = best.clone();
             }
+        }
+    }
 
-            self.mutation();
+    pub(crate) fn run_generations(&mut self, generations: u32) {
+        let last_generation = self
+            .current_generation
+            .saturating_add(generations)
+            .min(self.max_generations);
 
-            self.current_generation += 1;
+        while self.current_generation < last_generation && !self.stop_condition_met() {
+            self.run_generation();
         }
+    }
+
+    pub(crate) fn emigrants(&self, count: usize) -> Vec<Individual> {
+        self.population
+            .get_k_bests(count)
+            .into_iter()
+            .map(|index| self.population.individuals[index].clone())
+            .collect()
+    }
+
+    pub(crate) fn immigrate(&mut self, individuals: Vec<Individual>) {
+        for individual in individuals {
+            self.population.add(individual);
+        }
+
+        self.population
+            .select_survivors(self.population_size, self.elite_size);
+        self.update_best_from_population();
+    }
+
+    fn repair_best_infeasible(&mut self) {
+        let repaired = self
+            .infeasible_population
+            .best()
+            .and_then(|individual| self.repair.repair(individual));
+
+        if let Some(repaired) = repaired {
+            if self.should_update_best(&repaired) {
+                self.best = repaired;
+            }
+        }
+    }
+
+    pub(crate) fn update_solution(&mut self) {
+        self.repair_best_infeasible();
 
         let route_map: RouteMap = self
             .best
@@ -149,4 +467,12 @@ impl<'a, R: Rng + ?Sized> GeneticSolver<'a, R> {
 
         self.solution = Solution::new(&route_map, self.best.fitness);
     }
+
+    pub fn solve(&mut self) {
+        while !self.stop_condition_met() {
+            self.run_generation();
+        }
+
+        self.update_solution();
+    }
 }
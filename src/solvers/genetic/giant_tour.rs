@@ -0,0 +1,77 @@
+use std::{cmp, collections::HashSet};
+
+use rand::Rng;
+
+use super::{
+    individual::{Gene, Individual},
+    split::Split,
+};
+
+pub(crate) type GiantTour = Vec<Gene>;
+
+impl Individual {
+    pub(crate) fn giant_tour(&self) -> GiantTour {
+        self.chromosomes
+            .iter()
+            .flat_map(|chromosome| chromosome.customers())
+            .collect()
+    }
+
+    pub(crate) fn from_giant_tour(tour: &[Gene], split: &Split) -> Option<Individual> {
+        Some(Individual::new(split.run(tour)?))
+    }
+}
+
+pub(crate) fn generate_cut<R>(length: usize, rng: &mut R) -> (usize, usize)
+where
+    R: Rng + ?Sized,
+{
+    let a = rng.gen_range(0..=length);
+    let b = rng.gen_range(0..=length);
+
+    (cmp::min(a, b), cmp::max(a, b))
+}
+
+pub(crate) fn order_crossover<R>(tour1: &[Gene], tour2: &[Gene], rng: &mut R) -> GiantTour
+where
+    R: Rng + ?Sized,
+{
+    let (start, end) = generate_cut(tour1.len(), rng);
+    let slice = &tour1[start..end];
+
+    let mut remaining = tour2[end.min(tour2.len())..]
+        .iter()
+        .chain(tour2[..end.min(tour2.len())].iter())
+        .filter(|gene| !slice.contains(gene))
+        .copied();
+
+    let tail_length = tour1.len() - end;
+    let tail: Vec<Gene> = remaining.by_ref().take(tail_length).collect();
+    let head: Vec<Gene> = remaining.collect();
+
+    head.into_iter()
+        .chain(slice.iter().copied())
+        .chain(tail)
+        .collect()
+}
+
+pub(crate) fn inversion_mutation<R>(tour: &mut [Gene], rng: &mut R)
+where
+    R: Rng + ?Sized,
+{
+    let (start, end) = generate_cut(tour.len(), rng);
+
+    tour[start..end].reverse();
+}
+
+pub(crate) fn align_tours(tour1: &[Gene], tour2: &[Gene]) -> GiantTour {
+    let genes1: HashSet<Gene> = tour1.iter().copied().collect();
+    let genes2: HashSet<Gene> = tour2.iter().copied().collect();
+
+    tour2
+        .iter()
+        .filter(|gene| genes1.contains(gene))
+        .chain(tour1.iter().filter(|gene| !genes2.contains(gene)))
+        .copied()
+        .collect()
+}
@@ -0,0 +1,158 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    services::{distance::distance_oracle::IntoDistanceOracle, route::route_service::RouteMap},
+    solvers::solution::Solution,
+};
+
+use super::{
+    giant_tour::{inversion_mutation, order_crossover},
+    individual::Individual,
+    population::Population,
+    split::Split,
+};
+
+pub struct GiantTourSolver<'a, R: Rng + ?Sized> {
+    split: Split,
+    population_size: u32,
+    mutation_rate: f64,
+    population: Population,
+    max_generations: u32,
+    current_generation: u32,
+    pub solution: Solution,
+    best: Individual,
+    rng: &'a mut R,
+}
+
+impl<'a, R: Rng + ?Sized> GiantTourSolver<'a, R> {
+    pub fn new(
+        vehicles: Vec<Vehicle>,
+        distances: impl IntoDistanceOracle,
+        stops: Vec<Stop>,
+        population_size: u32,
+        mutation_rate: f64,
+        max_generations: u32,
+        rng: &'a mut R,
+    ) -> Self {
+        let split = Split::new(vehicles, distances, stops);
+
+        Self {
+            rng,
+            split,
+            mutation_rate,
+            population_size,
+            max_generations,
+            best: Default::default(),
+            solution: Default::default(),
+            population: Default::default(),
+            current_generation: Default::default(),
+        }
+    }
+
+    fn initialize_population(&mut self) {
+        self.population = Population::default();
+
+        for _ in 0..self.population_size {
+            let mut tour = self.split.customers.clone();
+            tour.shuffle(self.rng);
+
+            if let Some(individual) = Individual::from_giant_tour(&tour, &self.split) {
+                self.update_best(&individual);
+                self.population.push(individual);
+            }
+        }
+    }
+
+    fn tournament(&mut self) -> usize {
+        let size = self.population.individuals.len();
+        let candidate1 = self.rng.gen_range(0..size);
+        let candidate2 = self.rng.gen_range(0..size);
+
+        match self.population.individuals[candidate1].fitness
+            <= self.population.individuals[candidate2].fitness
+        {
+            true => candidate1,
+            false => candidate2,
+        }
+    }
+
+    fn worst(&self) -> Option<usize> {
+        self.population
+            .individuals
+            .iter()
+            .enumerate()
+            .max_by(|(_, individual1), (_, individual2)| {
+                individual1
+                    .fitness
+                    .partial_cmp(&individual2.fitness)
+                    .unwrap()
+            })
+            .map(|(index, _)| index)
+    }
+
+    fn update_best(&mut self, individual: &Individual) {
+        if individual.fitness < self.best.fitness {
+            self.best = individual.clone();
+        }
+    }
+
+    pub(super) fn generate_offspring(&mut self) -> Option<Individual> {
+        let (index1, index2) = (self.tournament(), self.tournament());
+
+        let parent1 = self.population.individuals[index1].giant_tour();
+        let parent2 = self.population.individuals[index2].giant_tour();
+
+        let mut tour = order_crossover(&parent1, &parent2, self.rng);
+
+        if self.rng.gen_bool(self.mutation_rate) {
+            inversion_mutation(&mut tour, self.rng);
+        }
+
+        Individual::from_giant_tour(&tour, &self.split)
+    }
+
+    fn run_generation(&mut self) {
+        for _ in 0..self.population_size {
+            let offspring = match self.generate_offspring() {
+                Some(offspring) => offspring,
+                None => continue,
+            };
+
+            let worst = match self.worst() {
+                Some(worst) => worst,
+                None => return,
+            };
+
+            if offspring.fitness >= self.population.individuals[worst].fitness {
+                continue;
+            }
+
+            self.update_best(&offspring);
+            self.population.replace(worst, offspring);
+        }
+    }
+
+    pub fn solve(&mut self) {
+        self.initialize_population();
+
+        if self.population.individuals.is_empty() {
+            return;
+        }
+
+        while self.current_generation < self.max_generations {
+            self.run_generation();
+            self.current_generation += 1;
+        }
+
+        let route_map: RouteMap = self
+            .best
+            .chromosomes
+            .iter()
+            .cloned()
+            .map(|chromosome| (chromosome.vehicle.id, chromosome))
+            .collect();
+
+        self.solution = Solution::new(&route_map, self.best.fitness);
+    }
+}
@@ -1,11 +1,12 @@
-use rand::{seq::IteratorRandom, thread_rng, Rng};
+use rand::{seq::IteratorRandom, Rng};
 
 use crate::{
     domain::{route::Route, stop::Stop},
     services::route::route_service::RouteService,
-    stop_swapper::{path::Path, StopSwapper},
 };
 
+use super::penalty::INITIAL_PENALTY;
+
 pub(super) type Gene = Stop;
 pub(super) type Chromosome = Route;
 pub(super) type GeneAddress = (usize, usize);
@@ -14,6 +15,7 @@ impl Default for Individual {
     fn default() -> Self {
         Self {
             fitness: f64::MAX,
+            penalty: INITIAL_PENALTY,
             chromosomes: Default::default(),
         }
     }
@@ -22,15 +24,17 @@ impl Default for Individual {
 #[derive(Clone)]
 pub(crate) struct Individual {
     pub(super) fitness: f64,
+    pub(super) penalty: f64,
     pub(super) chromosomes: Vec<Chromosome>,
 }
 
 impl Individual {
     pub fn new(chromosomes: Vec<Chromosome>) -> Self {
-        let fitness = Self::calculate_fitness(&chromosomes);
+        let fitness = Self::calculate_fitness(&chromosomes, INITIAL_PENALTY);
 
         Self {
             fitness,
+            penalty: INITIAL_PENALTY,
             chromosomes,
         }
     }
@@ -67,15 +71,33 @@ impl Individual {
         Individual::new(routes)
     }
 
-    fn calculate_fitness(chromosomes: &[Chromosome]) -> f64 {
+    fn calculate_fitness(chromosomes: &[Chromosome], penalty: f64) -> f64 {
         chromosomes
             .iter()
-            .map(|chromosome| chromosome.total_distance())
+            .map(|chromosome| {
+                chromosome.total_distance() + penalty * chromosome.excess_load() as f64
+            })
             .sum()
     }
 
     pub(crate) fn update_fitness(&mut self) {
-        self.fitness = Self::calculate_fitness(&self.chromosomes);
+        self.fitness = Self::calculate_fitness(&self.chromosomes, self.penalty);
+    }
+
+    pub(crate) fn set_penalty(&mut self, penalty: f64) {
+        self.penalty = penalty;
+        self.update_fitness();
+    }
+
+    pub(crate) fn excess_load(&self) -> u32 {
+        self.chromosomes
+            .iter()
+            .map(|chromosome| chromosome.excess_load())
+            .sum()
+    }
+
+    pub(crate) fn is_feasible(&self) -> bool {
+        self.excess_load() == 0
     }
 
     pub(crate) fn get_gene(&self, address: GeneAddress) -> Option<Gene> {
@@ -124,34 +146,6 @@ impl Individual {
             .choose(rng)
     }
 
-    pub(crate) fn choose_random_gene_pair<R>(
-        &self,
-        rng: &mut R,
-    ) -> Option<(GeneAddress, GeneAddress)>
-    where
-        R: Rng + ?Sized,
-    {
-        let (chromosome_index, chromosome): (usize, &Chromosome) = self
-            .chromosomes
-            .iter()
-            .enumerate()
-            .filter(|(_, chromosome)| chromosome.stops.len() > 3)
-            .choose(&mut thread_rng())?;
-
-        let addresses: Vec<GeneAddress> = chromosome
-            .stops
-            .iter()
-            .enumerate()
-            .skip(1)
-            .take(chromosome.stops.len() - 2)
-            .choose_multiple(rng, 2)
-            .iter()
-            .map(|(gene_index, _)| (chromosome_index, *gene_index))
-            .collect();
-
-        Some((addresses[0], addresses[1]))
-    }
-
     pub(crate) fn choose_random_gene<R>(&self, rng: &mut R) -> Option<GeneAddress>
     where
         R: Rng + ?Sized,
@@ -173,33 +167,4 @@ impl Individual {
 
         Some((chromosome_index, gene_index))
     }
-
-    pub(crate) fn swap_random_genes<R>(
-        &mut self,
-        stop_swapper: &StopSwapper,
-        rng: &mut R,
-    ) -> Option<()>
-    where
-        R: Rng + ?Sized,
-    {
-        let (address1, address2): (GeneAddress, GeneAddress) = self.choose_random_gene_pair(rng)?;
-
-        let path1 = Path::from_stop_index(
-            &self.chromosomes.get(address1.0)?.stops,
-            address1.1,
-            &stop_swapper.distance_service,
-        )?;
-
-        let path2 = Path::from_stop_index(
-            &self.chromosomes.get(address2.0)?.stops,
-            address2.1,
-            &stop_swapper.distance_service,
-        )?;
-
-        let swap_cost = stop_swapper.calculate_swap_cost(&path1, &path2);
-
-        self.swap_genes(address1, address2, swap_cost);
-
-        Some(())
-    }
 }
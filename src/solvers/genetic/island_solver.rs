@@ -0,0 +1,173 @@
+use std::thread;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    services::{
+        distance::distance_oracle::{IntoDistanceOracle, SharedDistanceOracle},
+        route::route_service::RouteService,
+    },
+    solvers::solution::Solution,
+};
+
+use super::{genetic_solver::GeneticSolver, individual::Individual};
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MigrationTopology {
+    Ring,
+    FullyConnected,
+}
+
+pub struct IslandSolver {
+    stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: SharedDistanceOracle,
+    number_of_islands: usize,
+    population_size: u32,
+    elite_size: usize,
+    mutation_rate: f64,
+    max_crossover_tries: u8,
+    max_generations: u32,
+    topology: MigrationTopology,
+    migration_interval: u32,
+    migrants: usize,
+    seed: u64,
+    pub solution: Solution,
+}
+
+impl IslandSolver {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        stops: Vec<Stop>,
+        vehicles: Vec<Vehicle>,
+        distances: impl IntoDistanceOracle,
+        number_of_islands: usize,
+        population_size: u32,
+        elite_size: usize,
+        mutation_rate: f64,
+        max_crossover_tries: u8,
+        max_generations: u32,
+        seed: u64,
+    ) -> Self {
+        Self {
+            seed,
+            stops,
+            vehicles,
+            elite_size,
+            mutation_rate,
+            population_size,
+            max_generations,
+            max_crossover_tries,
+            number_of_islands: number_of_islands.max(1),
+            distances: distances.into_oracle(),
+            topology: MigrationTopology::Ring,
+            migration_interval: max_generations.max(1),
+            migrants: 1,
+            solution: Default::default(),
+        }
+    }
+
+    pub fn with_migration(
+        mut self,
+        topology: MigrationTopology,
+        migration_interval: u32,
+        migrants: usize,
+    ) -> Self {
+        self.topology = topology;
+        self.migration_interval = migration_interval.max(1);
+        self.migrants = migrants;
+        self
+    }
+
+    fn island_rngs(&self) -> Vec<ChaCha8Rng> {
+        (0..self.number_of_islands)
+            .map(|island| {
+                let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+                rng.set_stream(island as u64);
+                rng
+            })
+            .collect()
+    }
+
+    fn create_island<'a>(&self, rng: &'a mut ChaCha8Rng) -> GeneticSolver<'a, ChaCha8Rng> {
+        let route_service =
+            RouteService::new(self.vehicles.clone(), &self.distances, self.stops.clone());
+
+        GeneticSolver::new(
+            self.stops.clone(),
+            &self.distances,
+            self.population_size,
+            self.elite_size,
+            self.mutation_rate,
+            self.max_crossover_tries,
+            self.max_generations,
+            route_service,
+            rng,
+        )
+    }
+
+    fn destinations(&self, island: usize) -> Vec<usize> {
+        match self.topology {
+            MigrationTopology::Ring => vec![(island + 1) % self.number_of_islands],
+            MigrationTopology::FullyConnected => (0..self.number_of_islands)
+                .filter(|destination| *destination != island)
+                .collect(),
+        }
+    }
+
+    fn migrate(&self, islands: &mut [GeneticSolver<ChaCha8Rng>]) {
+        if self.number_of_islands < 2 || self.migrants == 0 {
+            return;
+        }
+
+        let mut arrivals: Vec<Vec<Individual>> = vec![Vec::new(); islands.len()];
+
+        for (island, solver) in islands.iter().enumerate() {
+            let emigrants = solver.emigrants(self.migrants);
+
+            for destination in self.destinations(island) {
+                arrivals[destination].extend(emigrants.iter().cloned());
+            }
+        }
+
+        for (solver, immigrants) in islands.iter_mut().zip(arrivals) {
+            solver.immigrate(immigrants);
+        }
+    }
+
+    pub fn solve(&mut self) {
+        let mut rngs = self.island_rngs();
+        let mut islands: Vec<GeneticSolver<ChaCha8Rng>> =
+            rngs.iter_mut().map(|rng| self.create_island(rng)).collect();
+
+        let mut generation = 0;
+
+        while generation < self.max_generations {
+            let epoch = self
+                .migration_interval
+                .min(self.max_generations - generation);
+
+            thread::scope(|scope| {
+                for island in islands.iter_mut() {
+                    scope.spawn(move || island.run_generations(epoch));
+                }
+            });
+
+            generation += epoch;
+
+            if generation < self.max_generations {
+                self.migrate(&mut islands);
+            }
+        }
+
+        for island in islands.iter_mut() {
+            island.update_solution();
+
+            if island.solution.is_better_than(&self.solution) {
+                self.solution = std::mem::take(&mut island.solution);
+            }
+        }
+    }
+}
@@ -1,6 +1,17 @@
-mod crossover;
+pub mod crossover;
+mod diversity;
+mod education;
+mod giant_tour;
 mod individual;
+pub mod mutation;
+mod penalty;
 mod population;
+mod repair;
+pub mod selection;
+mod split;
+pub mod stopping_criteria;
 
 pub mod genetic_solver;
+pub mod giant_tour_solver;
+pub mod island_solver;
 mod tests;
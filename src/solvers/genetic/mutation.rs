@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use rand::{
+    seq::{index::sample, SliceRandom},
+    Rng,
+};
+
+use crate::domain::vehicle::Vehicle;
+
+use super::{
+    individual::{Chromosome, Gene, Individual},
+    split::Split,
+};
+
+const MIN_RATE: f64 = 0.01;
+const MAX_RATE: f64 = 1.0;
+const SUCCESS_RATIO: f64 = 0.2;
+const RATE_FACTOR: f64 = 1.2;
+const MIN_WEIGHT: f64 = 0.05;
+const REACTION: f64 = 0.2;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MutationKind {
+    Swap,
+    Inversion,
+    Insertion,
+    InterRoute,
+    Scramble,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum MutationSchedule {
+    #[default]
+    Constant,
+    Linear {
+        final_rate: f64,
+    },
+    SelfAdaptive,
+}
+
+#[derive(Clone, Copy, Default)]
+struct OperatorStats {
+    attempts: u32,
+    successes: u32,
+}
+
+pub(crate) struct Mutation {
+    pub(crate) rate: f64,
+    initial_rate: f64,
+    pub(crate) weights: Vec<(MutationKind, f64)>,
+    schedule: MutationSchedule,
+    stats: Vec<OperatorStats>,
+    split: Arc<Split>,
+}
+
+impl Mutation {
+    pub(crate) fn new(rate: f64, split: Arc<Split>, weights: Vec<(MutationKind, f64)>) -> Self {
+        let rate = rate.clamp(0.0, 1.0);
+
+        Self {
+            rate,
+            initial_rate: rate,
+            stats: vec![Default::default(); weights.len()],
+            weights,
+            schedule: Default::default(),
+            split,
+        }
+    }
+
+    pub(crate) fn set_weights(&mut self, weights: Vec<(MutationKind, f64)>) {
+        self.stats = vec![Default::default(); weights.len()];
+        self.weights = weights;
+    }
+
+    pub(crate) fn set_schedule(&mut self, schedule: MutationSchedule) {
+        self.schedule = schedule;
+    }
+
+    fn choose_route<R>(customers: &[Vec<Gene>], min_genes: usize, rng: &mut R) -> Option<usize>
+    where
+        R: Rng + ?Sized,
+    {
+        let candidates: Vec<usize> = (0..customers.len())
+            .filter(|index| customers[*index].len() >= min_genes)
+            .collect();
+
+        candidates.choose(rng).copied()
+    }
+
+    fn choose_positions<R>(length: usize, rng: &mut R) -> (usize, usize)
+    where
+        R: Rng + ?Sized,
+    {
+        let positions = sample(rng, length, 2);
+        let (first, second) = (positions.index(0), positions.index(1));
+
+        (first.min(second), first.max(second))
+    }
+
+    fn choose_segment<'c, R>(
+        customers: &'c mut [Vec<Gene>],
+        rng: &mut R,
+    ) -> Option<(&'c mut Vec<Gene>, usize, usize)>
+    where
+        R: Rng + ?Sized,
+    {
+        let route = Self::choose_route(customers, 2, rng)?;
+        let genes = &mut customers[route];
+        let (first, second) = Self::choose_positions(genes.len(), rng);
+
+        Some((genes, first, second))
+    }
+
+    fn mutate_genes<R>(kind: MutationKind, customers: &mut [Vec<Gene>], rng: &mut R) -> Option<()>
+    where
+        R: Rng + ?Sized,
+    {
+        match kind {
+            MutationKind::InterRoute => {
+                let source = Self::choose_route(customers, 1, rng)?;
+                let targets: Vec<usize> = (0..customers.len())
+                    .filter(|index| *index != source)
+                    .collect();
+                let target = *targets.choose(rng)?;
+
+                let gene = customers[source].remove(rng.gen_range(0..customers[source].len()));
+                let position = rng.gen_range(0..=customers[target].len());
+                customers[target].insert(position, gene);
+            }
+            MutationKind::Swap => {
+                let (genes, first, second) = Self::choose_segment(customers, rng)?;
+                genes.swap(first, second);
+            }
+            MutationKind::Inversion => {
+                let (genes, first, second) = Self::choose_segment(customers, rng)?;
+                genes[first..=second].reverse();
+            }
+            MutationKind::Scramble => {
+                let (genes, first, second) = Self::choose_segment(customers, rng)?;
+                genes[first..=second].shuffle(rng);
+            }
+            MutationKind::Insertion => {
+                let (genes, first, second) = Self::choose_segment(customers, rng)?;
+                let gene = genes.remove(first);
+                genes.insert(second, gene);
+            }
+        }
+
+        Some(())
+    }
+
+    pub(crate) fn apply<R>(
+        &self,
+        kind: MutationKind,
+        individual: &mut Individual,
+        rng: &mut R,
+    ) -> Option<()>
+    where
+        R: Rng + ?Sized,
+    {
+        let mut customers: Vec<Vec<Gene>> = individual
+            .chromosomes
+            .iter()
+            .map(Chromosome::customers)
+            .collect();
+        Self::mutate_genes(kind, &mut customers, rng)?;
+
+        let chromosomes = individual
+            .chromosomes
+            .iter()
+            .zip(customers.iter())
+            .map(|(chromosome, genes)| {
+                let vehicle = Vehicle::new(chromosome.vehicle.id, chromosome.vehicle.capacity);
+                self.split.build_route(vehicle, genes)
+            })
+            .collect::<Option<Vec<Chromosome>>>()?;
+
+        individual.chromosomes = chromosomes;
+        individual.update_fitness();
+
+        Some(())
+    }
+
+    pub(crate) fn mutate<R>(&mut self, individual: &mut Individual, rng: &mut R) -> bool
+    where
+        R: Rng + ?Sized,
+    {
+        let indexes: Vec<usize> = (0..self.weights.len()).collect();
+        let index = match indexes.choose_weighted(rng, |index| self.weights[*index].1) {
+            Ok(index) => *index,
+            Err(_) => return false,
+        };
+
+        let fitness = individual.fitness;
+        let mutated = self.apply(self.weights[index].0, individual, rng).is_some();
+
+        let stats = &mut self.stats[index];
+        stats.attempts += 1;
+
+        if mutated && individual.fitness < fitness {
+            stats.successes += 1;
+        }
+
+        mutated
+    }
+
+    pub(crate) fn update(&mut self, generation: u32, max_generations: u32) {
+        match self.schedule {
+            MutationSchedule::Constant => {}
+            MutationSchedule::Linear { final_rate } => {
+                let progress = match max_generations {
+                    0 => 1.0,
+                    _ => (generation as f64 / max_generations as f64).min(1.0),
+                };
+
+                self.rate = (self.initial_rate + (final_rate - self.initial_rate) * progress)
+                    .clamp(0.0, 1.0);
+            }
+            MutationSchedule::SelfAdaptive => self.adapt(),
+        }
+    }
+
+    fn adapt(&mut self) {
+        let attempts: u32 = self.stats.iter().map(|stats| stats.attempts).sum();
+
+        if attempts == 0 {
+            return;
+        }
+
+        let successes: u32 = self.stats.iter().map(|stats| stats.successes).sum();
+
+        self.rate = match successes as f64 / attempts as f64 > SUCCESS_RATIO {
+            true => self.rate * RATE_FACTOR,
+            false => self.rate / RATE_FACTOR,
+        }
+        .clamp(MIN_RATE, MAX_RATE);
+
+        for ((_, weight), stats) in self.weights.iter_mut().zip(self.stats.iter_mut()) {
+            if stats.attempts > 0 {
+                let ratio = stats.successes as f64 / stats.attempts as f64;
+                *weight = ((1.0 - REACTION) * *weight + REACTION * ratio).max(MIN_WEIGHT);
+            }
+
+            *stats = Default::default();
+        }
+    }
+}
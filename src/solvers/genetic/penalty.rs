@@ -0,0 +1,59 @@
+pub(crate) const INITIAL_PENALTY: f64 = 100.0;
+
+const MIN_PENALTY: f64 = 0.1;
+const MAX_PENALTY: f64 = 100_000.0;
+const TARGET_FEASIBLE_RATIO: f64 = 0.2;
+const TOLERANCE: f64 = 0.05;
+const INCREASE_FACTOR: f64 = 1.2;
+const DECREASE_FACTOR: f64 = 0.85;
+
+pub(crate) struct Penalty {
+    pub(crate) value: f64,
+    feasible: u32,
+    total: u32,
+}
+
+impl Default for Penalty {
+    fn default() -> Self {
+        Self::new(INITIAL_PENALTY)
+    }
+}
+
+impl Penalty {
+    pub(crate) fn new(value: f64) -> Self {
+        Self {
+            value: value.clamp(MIN_PENALTY, MAX_PENALTY),
+            feasible: Default::default(),
+            total: Default::default(),
+        }
+    }
+
+    pub(crate) fn record(&mut self, is_feasible: bool) {
+        self.total += 1;
+
+        if is_feasible {
+            self.feasible += 1;
+        }
+    }
+
+    pub(crate) fn adapt(&mut self) -> bool {
+        if self.total == 0 {
+            return false;
+        }
+
+        let ratio = self.feasible as f64 / self.total as f64;
+        let previous = self.value;
+
+        if ratio < TARGET_FEASIBLE_RATIO - TOLERANCE {
+            self.value *= INCREASE_FACTOR;
+        } else if ratio > TARGET_FEASIBLE_RATIO + TOLERANCE {
+            self.value *= DECREASE_FACTOR;
+        }
+
+        self.value = self.value.clamp(MIN_PENALTY, MAX_PENALTY);
+        self.feasible = 0;
+        self.total = 0;
+
+        self.value != previous
+    }
+}
@@ -1,22 +1,53 @@
+use std::collections::HashSet;
+
 use rand::Rng;
 
 use crate::services::route::route_service::RouteService;
 
-use super::individual::Individual;
+use super::{
+    diversity::{edge_distance, Edge},
+    individual::Individual,
+};
+
+const NUMBER_OF_CLOSE_INDIVIDUALS: usize = 3;
 
 #[derive(Default)]
 pub(crate) struct Population {
     pub(super) individuals: Vec<Individual>,
+    edges: Vec<HashSet<Edge>>,
+    distances: Vec<Vec<f64>>,
 }
 
 impl Population {
     #[allow(dead_code)]
     pub(super) fn new(individuals: Vec<Individual>) -> Self {
-        Self { individuals }
+        let mut population = Self::default();
+
+        for individual in individuals {
+            population.push(individual);
+        }
+
+        population
     }
 
-    pub(super) fn get_k_bests(&self, k: usize) -> &[Individual] {
-        &self.individuals[..k]
+    pub(super) fn get_k_bests(&self, k: usize) -> Vec<usize> {
+        let mut ranking: Vec<usize> = (0..self.individuals.len()).collect();
+
+        ranking.sort_by(|index1, index2| {
+            self.individuals[*index1]
+                .fitness
+                .partial_cmp(&self.individuals[*index2].fitness)
+                .unwrap()
+        });
+
+        ranking.truncate(k);
+        ranking
+    }
+
+    pub(super) fn best(&self) -> Option<&Individual> {
+        self.get_k_bests(1)
+            .first()
+            .map(|index| &self.individuals[*index])
     }
 
     pub(crate) fn from_random<R>(size: u32, rng: &mut R, route_service: &mut RouteService) -> Self
@@ -27,11 +58,195 @@ impl Population {
 
         for _ in 0..size {
             let individual = Individual::from_random(rng, route_service);
-            population.individuals.push(individual);
+            population.push(individual);
 
             route_service.reset();
         }
 
         population
     }
+
+    fn insert(&mut self, individual: Individual, edges: HashSet<Edge>) {
+        let mut row: Vec<f64> = self
+            .edges
+            .iter()
+            .map(|other_edges| edge_distance(&edges, other_edges))
+            .collect();
+
+        for (distances, distance) in self.distances.iter_mut().zip(row.iter()) {
+            distances.push(*distance);
+        }
+
+        row.push(0.0);
+
+        self.individuals.push(individual);
+        self.edges.push(edges);
+        self.distances.push(row);
+    }
+
+    pub(super) fn push(&mut self, individual: Individual) {
+        let edges = individual.edges();
+        self.insert(individual, edges);
+    }
+
+    pub(super) fn replace(&mut self, index: usize, individual: Individual) {
+        self.individuals[index] = individual;
+        self.refresh(index);
+    }
+
+    pub(super) fn refresh(&mut self, index: usize) {
+        self.edges[index] = self.individuals[index].edges();
+
+        for other in 0..self.individuals.len() {
+            if other == index {
+                continue;
+            }
+
+            let distance = edge_distance(&self.edges[index], &self.edges[other]);
+            self.distances[index][other] = distance;
+            self.distances[other][index] = distance;
+        }
+    }
+
+    pub(super) fn remove(&mut self, index: usize) -> Individual {
+        self.edges.remove(index);
+        self.distances.remove(index);
+
+        for distances in self.distances.iter_mut() {
+            distances.remove(index);
+        }
+
+        self.individuals.remove(index)
+    }
+
+    pub(super) fn distance(&self, index1: usize, index2: usize) -> f64 {
+        self.distances[index1][index2]
+    }
+
+    pub(super) fn add(&mut self, individual: Individual) -> bool {
+        let edges = individual.edges();
+
+        let is_duplicate =
+            self.individuals
+                .iter()
+                .zip(self.edges.iter())
+                .any(|(other, other_edges)| {
+                    other.fitness == individual.fitness && edge_distance(&edges, other_edges) == 0.0
+                });
+
+        if is_duplicate {
+            return false;
+        }
+
+        self.insert(individual, edges);
+        true
+    }
+
+    pub(super) fn diversity_contribution(&self, index: usize) -> f64 {
+        let mut distances: Vec<f64> = self.distances[index]
+            .iter()
+            .enumerate()
+            .filter(|(other, _)| *other != index)
+            .map(|(_, distance)| *distance)
+            .collect();
+
+        if distances.is_empty() {
+            return 0.0;
+        }
+
+        distances.sort_by(|distance1, distance2| distance1.partial_cmp(distance2).unwrap());
+
+        let closest = &distances[..NUMBER_OF_CLOSE_INDIVIDUALS.min(distances.len())];
+
+        closest.iter().sum::<f64>() / closest.len() as f64
+    }
+
+    pub(super) fn diversity(&self) -> f64 {
+        let size = self.individuals.len();
+
+        if size < 2 {
+            return 0.0;
+        }
+
+        let total: f64 = (0..size)
+            .flat_map(|index1| (index1 + 1..size).map(move |index2| (index1, index2)))
+            .map(|(index1, index2)| self.distance(index1, index2))
+            .sum();
+
+        total / (size * (size - 1) / 2) as f64
+    }
+
+    fn normalized_ranks(values: &[f64]) -> Vec<f64> {
+        let mut ranking: Vec<usize> = (0..values.len()).collect();
+        ranking.sort_by(|index1, index2| values[*index1].partial_cmp(&values[*index2]).unwrap());
+
+        let mut ranks = vec![0.0; values.len()];
+        let denominator = values.len().saturating_sub(1).max(1) as f64;
+
+        for (rank, index) in ranking.into_iter().enumerate() {
+            ranks[index] = rank as f64 / denominator;
+        }
+
+        ranks
+    }
+
+    pub(super) fn biased_fitness(&self, elite_size: usize) -> Vec<f64> {
+        let size = self.individuals.len();
+
+        let costs: Vec<f64> = self
+            .individuals
+            .iter()
+            .map(|individual| individual.fitness)
+            .collect();
+
+        let diversities: Vec<f64> = (0..size)
+            .map(|index| -self.diversity_contribution(index))
+            .collect();
+
+        let cost_ranks = Self::normalized_ranks(&costs);
+        let diversity_ranks = Self::normalized_ranks(&diversities);
+        let diversity_weight = 1.0 - elite_size.min(size) as f64 / size.max(1) as f64;
+
+        cost_ranks
+            .iter()
+            .zip(diversity_ranks.iter())
+            .map(|(cost_rank, diversity_rank)| cost_rank + diversity_weight * diversity_rank)
+            .collect()
+    }
+
+    fn find_clone(&self) -> Option<usize> {
+        (0..self.individuals.len()).find(|index| {
+            (0..*index).any(|other| {
+                self.individuals[other].fitness == self.individuals[*index].fitness
+                    && self.distance(other, *index) == 0.0
+            })
+        })
+    }
+
+    fn find_worst_biased(&self, elite_size: usize) -> Option<usize> {
+        let elites = self.get_k_bests(elite_size);
+        let biased_fitness = self.biased_fitness(elite_size);
+
+        (0..self.individuals.len())
+            .filter(|index| !elites.contains(index))
+            .max_by(|index1, index2| {
+                biased_fitness[*index1]
+                    .partial_cmp(&biased_fitness[*index2])
+                    .unwrap()
+            })
+    }
+
+    pub(super) fn select_survivors(&mut self, size: usize, elite_size: usize) {
+        while self.individuals.len() > size {
+            let removed = match self.find_clone() {
+                Some(index) => index,
+                None => match self.find_worst_biased(elite_size) {
+                    Some(index) => index,
+                    None => return,
+                },
+            };
+
+            self.remove(removed);
+        }
+    }
 }
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use crate::domain::vehicle::Vehicle;
+
+use super::{
+    individual::{Chromosome, Gene, Individual},
+    split::Split,
+};
+
+pub(crate) struct Repair {
+    split: Arc<Split>,
+}
+
+impl Repair {
+    pub(crate) fn new(split: Arc<Split>) -> Self {
+        Self { split }
+    }
+
+    fn distance(&self, from: &Gene, to: &Gene) -> f64 {
+        self.split
+            .distance_service
+            .get_distance(from, to)
+            .unwrap_or(f64::MAX)
+    }
+
+    fn load(genes: &[Gene]) -> u32 {
+        genes.iter().map(|gene| gene.usage).sum()
+    }
+
+    fn removal_gain(&self, depot: Gene, genes: &[Gene], index: usize) -> f64 {
+        let previous = if index == 0 { depot } else { genes[index - 1] };
+        let next = genes.get(index + 1).copied().unwrap_or(depot);
+
+        self.distance(&previous, &genes[index]) + self.distance(&genes[index], &next)
+            - self.distance(&previous, &next)
+    }
+
+    fn insertion_cost(&self, depot: Gene, genes: &[Gene], index: usize, gene: &Gene) -> f64 {
+        let previous = if index == 0 { depot } else { genes[index - 1] };
+        let next = genes.get(index).copied().unwrap_or(depot);
+
+        self.distance(&previous, gene) + self.distance(gene, &next)
+            - self.distance(&previous, &next)
+    }
+
+    fn remove_excess(&self, depot: Gene, genes: &mut Vec<Gene>, capacity: u32) -> Vec<Gene> {
+        let mut removed = Vec::new();
+
+        while Self::load(genes) > capacity && !genes.is_empty() {
+            let index = (0..genes.len())
+                .max_by(|index1, index2| {
+                    self.removal_gain(depot, genes, *index1)
+                        .total_cmp(&self.removal_gain(depot, genes, *index2))
+                })
+                .unwrap();
+
+            removed.push(genes.remove(index));
+        }
+
+        removed
+    }
+
+    fn reinsert(
+        &self,
+        depot: Gene,
+        routes: &mut [Vec<Gene>],
+        capacities: &[u32],
+        gene: Gene,
+    ) -> Option<()> {
+        let (route, index, _) = routes
+            .iter()
+            .enumerate()
+            .filter(|(route, genes)| Self::load(genes) + gene.usage <= capacities[*route])
+            .flat_map(|(route, genes)| {
+                (0..=genes.len()).map(move |index| {
+                    (
+                        route,
+                        index,
+                        self.insertion_cost(depot, genes, index, &gene),
+                    )
+                })
+            })
+            .min_by(|a, b| a.2.total_cmp(&b.2))?;
+
+        routes[route].insert(index, gene);
+        Some(())
+    }
+
+    pub(crate) fn repair(&self, individual: &Individual) -> Option<Individual> {
+        let depot = self.split.depot?;
+        let vehicles: Vec<Vehicle> = individual
+            .chromosomes
+            .iter()
+            .map(|chromosome| Vehicle::new(chromosome.vehicle.id, chromosome.vehicle.capacity))
+            .collect();
+        let capacities: Vec<u32> = vehicles
+            .iter()
+            .map(|vehicle| self.split.capacity(vehicle))
+            .collect();
+
+        let mut routes: Vec<Vec<Gene>> = individual
+            .chromosomes
+            .iter()
+            .map(Chromosome::customers)
+            .collect();
+
+        let mut removed: Vec<Gene> = routes
+            .iter_mut()
+            .zip(capacities.iter())
+            .flat_map(|(genes, capacity)| self.remove_excess(depot, genes, *capacity))
+            .collect();
+
+        removed.sort_by_key(|gene| std::cmp::Reverse(gene.usage));
+
+        for gene in removed {
+            self.reinsert(depot, &mut routes, &capacities, gene)?;
+        }
+
+        let chromosomes = vehicles
+            .into_iter()
+            .zip(routes.iter())
+            .map(|(vehicle, genes)| self.split.build_route(vehicle, genes))
+            .collect::<Option<Vec<Chromosome>>>()?;
+
+        let mut repaired = Individual::new(chromosomes);
+        repaired.set_penalty(individual.penalty);
+
+        Some(repaired)
+    }
+}
@@ -0,0 +1,131 @@
+use std::borrow::Borrow;
+
+use rand::{
+    seq::{IteratorRandom, SliceRandom},
+    Rng,
+};
+
+use super::individual::Individual;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum SelectionStrategy {
+    Tournament { size: usize },
+    RankBased,
+    Roulette,
+}
+
+impl Default for SelectionStrategy {
+    fn default() -> Self {
+        Self::Tournament { size: 2 }
+    }
+}
+
+impl SelectionStrategy {
+    pub(crate) fn select<I, R>(&self, individuals: &[I], rng: &mut R) -> Option<usize>
+    where
+        I: Borrow<Individual>,
+        R: Rng + ?Sized,
+    {
+        match self {
+            Self::Tournament { size } => Self::tournament(individuals, *size, rng),
+            Self::RankBased => Self::rank_based(individuals, rng),
+            Self::Roulette => Self::roulette(individuals, rng),
+        }
+    }
+
+    fn tournament<I, R>(individuals: &[I], size: usize, rng: &mut R) -> Option<usize>
+    where
+        I: Borrow<Individual>,
+        R: Rng + ?Sized,
+    {
+        (0..size.max(1))
+            .map(|_| rng.gen_range(0..individuals.len()))
+            .collect::<Vec<usize>>()
+            .into_iter()
+            .min_by(|index1, index2| {
+                individuals[*index1]
+                    .borrow()
+                    .fitness
+                    .partial_cmp(&individuals[*index2].borrow().fitness)
+                    .unwrap()
+            })
+    }
+
+    fn rank_based<I, R>(individuals: &[I], rng: &mut R) -> Option<usize>
+    where
+        I: Borrow<Individual>,
+        R: Rng + ?Sized,
+    {
+        let mut ranking: Vec<usize> = (0..individuals.len()).collect();
+
+        ranking.sort_by(|index1, index2| {
+            individuals[*index1]
+                .borrow()
+                .fitness
+                .partial_cmp(&individuals[*index2].borrow().fitness)
+                .unwrap()
+        });
+
+        let weighted: Vec<(usize, usize)> = ranking
+            .into_iter()
+            .enumerate()
+            .map(|(rank, index)| (index, individuals.len() - rank))
+            .collect();
+
+        weighted
+            .choose_weighted(rng, |(_, weight)| *weight)
+            .ok()
+            .map(|(index, _)| *index)
+    }
+
+    pub(crate) fn inverted_cost(individual: &Individual) -> f64 {
+        1.0 / (individual.fitness + f64::EPSILON)
+    }
+
+    fn roulette<I, R>(individuals: &[I], rng: &mut R) -> Option<usize>
+    where
+        I: Borrow<Individual>,
+        R: Rng + ?Sized,
+    {
+        let weighted: Vec<(usize, f64)> = individuals
+            .iter()
+            .map(|individual| Self::inverted_cost(individual.borrow()))
+            .enumerate()
+            .collect();
+
+        match weighted.choose_weighted(rng, |(_, weight)| *weight) {
+            Ok((index, _)) => Some(*index),
+            Err(_) => (0..individuals.len()).choose(rng),
+        }
+    }
+
+    pub(crate) fn select_pair<I, R>(
+        &self,
+        individuals: &[I],
+        rng: &mut R,
+        max_tries: u8,
+    ) -> Option<(usize, usize)>
+    where
+        I: Borrow<Individual>,
+        R: Rng + ?Sized,
+    {
+        if individuals.len() < 2 {
+            return None;
+        }
+
+        let first = self.select(individuals, rng)?;
+
+        for _ in 0..max_tries {
+            let second = self.select(individuals, rng)?;
+
+            if second != first {
+                return Some((first, second));
+            }
+        }
+
+        (0..individuals.len())
+            .filter(|index| *index != first)
+            .choose(rng)
+            .map(|second| (first, second))
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    lower_bound::vehicle_bound::effective_capacity,
+    services::distance::{distance_oracle::IntoDistanceOracle, distance_service::DistanceService},
+};
+
+use super::individual::Gene;
+
+const DEPOT_ID: u32 = 0;
+
+type Label = Option<(f64, usize)>;
+
+pub(crate) struct Split {
+    pub(crate) depot: Option<Stop>,
+    vehicles: Vec<Vehicle>,
+    pub(crate) customers: Vec<Gene>,
+    pub(crate) distance_service: DistanceService,
+}
+
+impl Split {
+    pub(crate) fn new(
+        vehicles: Vec<Vehicle>,
+        distances: impl IntoDistanceOracle,
+        stops: Vec<Stop>,
+    ) -> Self {
+        let depot = stops.iter().find(|stop| stop.id == DEPOT_ID).copied();
+        let distance_service = DistanceService::new(stops.clone(), distances);
+
+        let customers = stops
+            .into_iter()
+            .filter(|stop| stop.id != DEPOT_ID)
+            .filter(|stop| {
+                vehicles
+                    .iter()
+                    .any(|vehicle| stop.usage <= effective_capacity(vehicle, depot.as_ref()))
+            })
+            .collect();
+
+        Self {
+            depot,
+            vehicles,
+            customers,
+            distance_service,
+        }
+    }
+
+    pub(crate) fn capacity(&self, vehicle: &Vehicle) -> u32 {
+        effective_capacity(vehicle, self.depot.as_ref())
+    }
+
+    fn next_labels(
+        &self,
+        depot: &Stop,
+        tour: &[Gene],
+        previous: &[Label],
+        vehicle: &Vehicle,
+    ) -> Vec<Label> {
+        let capacity = self.capacity(vehicle);
+        let mut labels: Vec<Label> = vec![None; previous.len()];
+
+        for (start, label) in previous.iter().enumerate() {
+            let (cost, _) = match label {
+                Some(label) => *label,
+                None => continue,
+            };
+
+            let first = match tour.get(start) {
+                Some(first) => first,
+                None => continue,
+            };
+
+            let Some(leaving) = self.distance_service.get_distance(depot, first) else {
+                continue;
+            };
+
+            let mut load = 0;
+            let mut inner = 0.0;
+
+            for (end, next) in labels.iter_mut().enumerate().skip(start + 1) {
+                let last = &tour[end - 1];
+                load += last.usage;
+
+                if load > capacity {
+                    break;
+                }
+
+                if end - 1 > start {
+                    match self.distance_service.get_distance(&tour[end - 2], last) {
+                        Some(distance) => inner += distance,
+                        None => break,
+                    }
+                }
+
+                let returning = match self.distance_service.get_distance(last, depot) {
+                    Some(returning) => returning,
+                    None => continue,
+                };
+
+                let route_cost = leaving + inner + returning;
+
+                if next.map_or(true, |(current, _)| cost + route_cost < current) {
+                    *next = Some((cost + route_cost, start));
+                }
+            }
+        }
+
+        for (start, (label, next)) in previous.iter().zip(labels.iter_mut()).enumerate() {
+            if let Some((cost, _)) = label {
+                if next.map_or(true, |(current, _)| *cost <= current) {
+                    *next = Some((*cost, start));
+                }
+            }
+        }
+
+        labels
+    }
+
+    fn labels(&self, depot: &Stop, tour: &[Gene]) -> Vec<Vec<Label>> {
+        let mut first: Vec<Label> = vec![None; tour.len() + 1];
+        first[0] = Some((0.0, 0));
+
+        let mut labels = vec![first];
+
+        for vehicle in self.vehicles.iter() {
+            let next = self.next_labels(depot, tour, labels.last().unwrap(), vehicle);
+            labels.push(next);
+        }
+
+        labels
+    }
+
+    pub(crate) fn build_route(&self, vehicle: Vehicle, genes: &[Gene]) -> Option<Route> {
+        let depot = self.depot?;
+        let mut route = Route::new(vehicle);
+        route.add_stop(depot, 0.0).ok()?;
+
+        let mut previous = depot;
+
+        for gene in genes.iter().chain([depot].iter()) {
+            let distance = self.distance_service.get_distance(&previous, gene)?;
+            route.add_stop(*gene, distance).ok()?;
+            previous = *gene;
+        }
+
+        Some(route)
+    }
+
+    /// Returns `None` when the tour can't be split, including when the stops have no depot.
+    pub(crate) fn run(&self, tour: &[Gene]) -> Option<Vec<Route>> {
+        let labels = self.labels(self.depot.as_ref()?, tour);
+        let mut end = tour.len();
+        let mut ranges = Vec::new();
+
+        for index in (1..=self.vehicles.len()).rev() {
+            let (_, start) = labels[index][end]?;
+            ranges.push(start..end);
+            end = start;
+        }
+
+        ranges
+            .into_iter()
+            .rev()
+            .zip(self.vehicles.iter())
+            .map(|(range, vehicle)| self.build_route(*vehicle, &tour[range]))
+            .collect()
+    }
+}
@@ -0,0 +1,32 @@
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub struct StoppingCriteria {
+    pub max_generations_without_improvement: Option<u32>,
+    pub target_fitness: Option<f64>,
+    pub min_diversity: Option<f64>,
+}
+
+impl StoppingCriteria {
+    pub(crate) fn is_met(
+        &self,
+        generations_without_improvement: u32,
+        best_fitness: f64,
+        diversity: impl FnOnce() -> f64,
+    ) -> bool {
+        if self
+            .max_generations_without_improvement
+            .is_some_and(|max| generations_without_improvement >= max)
+        {
+            return true;
+        }
+
+        if self
+            .target_fitness
+            .is_some_and(|target| best_fitness <= target)
+        {
+            return true;
+        }
+
+        self.min_diversity
+            .is_some_and(|min_diversity| diversity() < min_diversity)
+    }
+}
@@ -0,0 +1,128 @@
+use rstest::{fixture, rstest};
+
+use crate::domain::stop::Stop;
+use crate::solvers::genetic::{individual::Individual, population::Population};
+
+use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
+use crate::tests::fixtures::stops_fixture::stops;
+
+type IndividualBuilder = Box<dyn Fn([&[usize]; 2]) -> Individual>;
+
+#[fixture]
+fn individual_builder(stops: Vec<Stop>, route_factory: RouteFactory) -> IndividualBuilder {
+    Box::new(move |routes| {
+        let chromosomes = routes
+            .iter()
+            .map(|route| {
+                let route_stops = route.iter().map(|index| stops[*index]).collect();
+                route_factory(route_stops)
+            })
+            .collect();
+
+        Individual::new(chromosomes)
+    })
+}
+
+#[rstest]
+fn broken_pairs_distance_ignores_direction(individual_builder: IndividualBuilder) {
+    let individual1 = individual_builder([&[0, 1, 2, 0], &[0, 3, 0]]);
+    let individual2 = individual_builder([&[0, 2, 1, 0], &[0, 3, 0]]);
+
+    assert_eq!(individual1.broken_pairs_distance(&individual2), 0.0);
+}
+
+#[rstest]
+fn broken_pairs_distance_counts_broken_edges(individual_builder: IndividualBuilder) {
+    let individual1 = individual_builder([&[0, 1, 2, 0], &[0, 3, 0]]);
+    let individual2 = individual_builder([&[0, 1, 3, 0], &[0, 2, 0]]);
+
+    assert_eq!(individual1.broken_pairs_distance(&individual2), 0.25);
+}
+
+#[rstest]
+fn population_rejects_duplicates(individual_builder: IndividualBuilder) {
+    let mut population = Population::default();
+
+    assert!(population.add(individual_builder([&[0, 1, 2, 0], &[0, 3, 0]])));
+    assert!(!population.add(individual_builder([&[0, 2, 1, 0], &[0, 3, 0]])));
+    assert!(population.add(individual_builder([&[0, 1, 3, 0], &[0, 2, 0]])));
+
+    assert_eq!(population.individuals.len(), 2);
+}
+
+#[rstest]
+fn biased_fitness_rewards_cost_and_diversity(individual_builder: IndividualBuilder) {
+    let population = Population::new(vec![
+        individual_builder([&[0, 2, 3, 1, 0], &[0, 0]]),
+        individual_builder([&[0, 1, 2, 0], &[0, 3, 0]]),
+        individual_builder([&[0, 1, 2, 3, 0], &[0, 0]]),
+    ]);
+
+    let biased_fitness = population.biased_fitness(1);
+
+    assert!(biased_fitness[0] < biased_fitness[2]);
+    assert!(population.diversity_contribution(1) > 0.0);
+}
+
+#[rstest]
+fn survivor_selection_keeps_best(individual_builder: IndividualBuilder) {
+    let mut population = Population::new(vec![
+        individual_builder([&[0, 1, 2, 3, 0], &[0, 0]]),
+        individual_builder([&[0, 2, 3, 1, 0], &[0, 0]]),
+        individual_builder([&[0, 1, 2, 0], &[0, 3, 0]]),
+        individual_builder([&[0, 2, 1, 0], &[0, 3, 0]]),
+    ]);
+
+    population.select_survivors(2, 1);
+
+    assert_eq!(population.individuals.len(), 2);
+    assert_eq!(population.best().unwrap().fitness, 8.0);
+}
+
+#[rstest]
+fn survivor_selection_keeps_elites(individual_builder: IndividualBuilder) {
+    let mut population = Population::new(vec![
+        individual_builder([&[0, 1, 2, 0], &[0, 3, 0]]),
+        individual_builder([&[0, 1, 0], &[0, 2, 3, 0]]),
+        individual_builder([&[0, 1, 2, 3, 0], &[0, 0]]),
+        individual_builder([&[0, 2, 0], &[0, 1, 3, 0]]),
+        individual_builder([&[0, 2, 3, 1, 0], &[0, 0]]),
+        individual_builder([&[0, 2, 1, 0], &[0, 3, 0]]),
+    ]);
+
+    population.select_survivors(2, 2);
+
+    let mut fitness: Vec<f64> = population
+        .individuals
+        .iter()
+        .map(|individual| individual.fitness)
+        .collect();
+    fitness.sort_by(|fitness1, fitness2| fitness1.partial_cmp(fitness2).unwrap());
+
+    assert_eq!(fitness, vec![8.0, 10.0]);
+}
+
+#[rstest]
+fn cached_distances_follow_population_changes(individual_builder: IndividualBuilder) {
+    let mut population = Population::new(vec![
+        individual_builder([&[0, 1, 2, 3, 0], &[0, 0]]),
+        individual_builder([&[0, 2, 3, 1, 0], &[0, 0]]),
+        individual_builder([&[0, 1, 2, 0], &[0, 3, 0]]),
+    ]);
+
+    population.add(individual_builder([&[0, 1, 0], &[0, 2, 3, 0]]));
+    population.remove(1);
+    population.replace(0, individual_builder([&[0, 2, 0], &[0, 1, 3, 0]]));
+
+    let size = population.individuals.len();
+
+    for index1 in 0..size {
+        for index2 in 0..size {
+            assert_eq!(
+                population.distance(index1, index2),
+                population.individuals[index1]
+                    .broken_pairs_distance(&population.individuals[index2])
+            );
+        }
+    }
+}
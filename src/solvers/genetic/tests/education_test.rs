@@ -0,0 +1,107 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::domain::stop::Stop;
+use crate::services::distance::distance_service::DistanceMatrix;
+use crate::solvers::genetic::{
+    education::Education, genetic_solver::GeneticSolver, individual::Individual,
+};
+use crate::solvers::solution::Solution;
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
+use crate::tests::fixtures::services_fixture::{route_service_factory, RouteServiceFactory};
+use crate::tests::fixtures::stops_fixture::stops;
+
+fn actual_distance(distances: &DistanceMatrix, individual: &Individual) -> f64 {
+    individual
+        .chromosomes
+        .iter()
+        .flat_map(|chromosome| chromosome.stops.windows(2))
+        .map(|pair| {
+            distances
+                .get(&(pair[0].id, pair[1].id))
+                .copied()
+                .unwrap_or(0.0)
+        })
+        .sum()
+}
+
+fn solution_distance(distances: &DistanceMatrix, solution: &Solution) -> f64 {
+    solution
+        .result
+        .values()
+        .flat_map(|route| route.windows(2))
+        .map(|pair| distances.get(&(pair[0], pair[1])).copied().unwrap_or(0.0))
+        .sum()
+}
+
+#[rstest]
+fn education_improves_individual(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_factory: RouteFactory,
+) {
+    let education = Education::new(stops.clone(), &distances, 1.0);
+
+    let route1 = route_factory(vec![stops[0], stops[1], stops[2], stops[0]]);
+    let route2 = route_factory(vec![stops[0], stops[3], stops[0]]);
+
+    let mut individual = Individual::new(vec![route1, route2]);
+    let initial_fitness = individual.fitness;
+
+    education.educate(&mut individual).unwrap();
+
+    assert!(individual.fitness < initial_fitness);
+    assert_eq!(individual.fitness, 8.0);
+    assert_eq!(individual.fitness, actual_distance(&distances, &individual));
+}
+
+#[rstest]
+fn education_restores_consistent_fitness(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_factory: RouteFactory,
+) {
+    let education = Education::new(stops.clone(), &distances, 1.0);
+
+    let route1 = route_factory(vec![stops[0], stops[2], stops[3], stops[1], stops[0]]);
+    let route2 = route_factory(vec![stops[0]]);
+
+    let mut individual = Individual::new(vec![route1, route2]);
+    individual.swap_genes((0, 1), (0, 3), -5.0).unwrap();
+
+    education.educate(&mut individual).unwrap();
+
+    assert_eq!(individual.fitness, actual_distance(&distances, &individual));
+}
+
+#[rstest]
+fn memetic_genetic_algorithm_reports_actual_distance(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_service_factory: RouteServiceFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    let mut solver = GeneticSolver::new(
+        stops,
+        &distances,
+        10,
+        3,
+        0.0,
+        10,
+        5,
+        route_service_factory(2),
+        &mut rng,
+    )
+    .with_education(1.0);
+
+    solver.solve();
+
+    assert_eq!(
+        solver.solution.total_distance,
+        solution_distance(&distances, &solver.solution)
+    );
+}
@@ -1,10 +1,17 @@
+use std::collections::HashMap;
+
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 use rstest::rstest;
 
 use crate::{
-    domain::stop::Stop, services::distance::distance_service::DistanceMatrix,
-    solvers::genetic::genetic_solver::GeneticSolver,
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceMatrix,
+    solvers::genetic::{
+        crossover::crossover_kind::CrossoverKind, genetic_solver::GeneticSolver,
+        selection::SelectionStrategy,
+    },
+    solvers::solution::Solution,
 };
 
 use crate::tests::fixtures::distances_fixture::distances;
@@ -40,3 +47,103 @@ fn test_genetic_algorithm_can_generate_a_good_route(
     assert_ne!(solution_v1.len(), 0);
     assert_ne!(solution_v2.len(), 0);
 }
+
+#[rstest]
+fn test_genetic_algorithm_with_mixed_crossovers(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_service_factory: RouteServiceFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    let route_service = route_service_factory(2);
+    let mut solver = GeneticSolver::new(
+        stops,
+        &distances,
+        10,
+        3,
+        0.05,
+        10,
+        5,
+        route_service,
+        &mut rng,
+    )
+    .with_crossovers(vec![
+        (CrossoverKind::Order, 0.25),
+        (CrossoverKind::PartiallyMapped, 0.25),
+        (CrossoverKind::EdgeRecombination, 0.25),
+        (CrossoverKind::SelectiveRouteExchange, 0.25),
+    ]);
+
+    solver.solve();
+
+    assert!(solver.solution.result.contains_key(&0));
+    assert!(solver.solution.result.contains_key(&1));
+}
+
+#[rstest]
+#[case(SelectionStrategy::Tournament { size: 3 })]
+#[case(SelectionStrategy::RankBased)]
+#[case(SelectionStrategy::Roulette)]
+fn test_genetic_algorithm_with_selection_strategies(
+    #[case] selection: SelectionStrategy,
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_service_factory: RouteServiceFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    let route_service = route_service_factory(2);
+    let mut solver = GeneticSolver::new(
+        stops,
+        &distances,
+        10,
+        3,
+        0.05,
+        10,
+        5,
+        route_service,
+        &mut rng,
+    )
+    .with_selection(selection);
+
+    solver.solve();
+
+    assert!(solver.solution.total_distance < f64::MAX);
+    assert!(solver.solution.result.contains_key(&0));
+}
+
+fn yesterday() -> Solution {
+    Solution {
+        total_distance: 0.0,
+        result: HashMap::from([(0, vec![0, 2, 3, 1, 0]), (1, vec![0, 0])]),
+    }
+}
+
+#[rstest]
+fn genetic_algorithm_is_seeded_with_initial_solution(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_service_factory: RouteServiceFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    let mut solver = GeneticSolver::new(
+        stops,
+        &distances,
+        10,
+        3,
+        0.05,
+        10,
+        0,
+        route_service_factory(2),
+        &mut rng,
+    )
+    .with_initial_solution(&yesterday())
+    .unwrap();
+
+    solver.solve();
+
+    assert_eq!(solver.solution.total_distance, 8.0);
+    assert_eq!(solver.solution.result, yesterday().result);
+}
@@ -0,0 +1,34 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop, services::distance::distance_service::DistanceMatrix,
+    solvers::genetic::giant_tour_solver::GiantTourSolver,
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+#[rstest]
+fn test_giant_tour_solver_can_generate_a_good_route(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    let mut solver =
+        GiantTourSolver::new(vehicle_factory(2), &distances, stops, 10, 0.2, 5, &mut rng);
+
+    solver.solve();
+
+    let solution_v1 = solver.solution.result.get(&0).unwrap();
+    let solution_v2 = solver.solution.result.get(&1).unwrap();
+
+    assert_eq!(solver.solution.total_distance, 8.0);
+    assert_eq!(solution_v1.first(), Some(&0));
+    assert_eq!(solution_v2.last(), Some(&0));
+    assert_eq!(solution_v1.len() + solution_v2.len(), 7);
+}
@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::domain::stop::Stop;
+use crate::solvers::genetic::giant_tour::{inversion_mutation, order_crossover};
+use crate::solvers::genetic::individual::Individual;
+
+use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
+use crate::tests::fixtures::stops_fixture::stops;
+
+fn ids(tour: &[Stop]) -> Vec<u32> {
+    tour.iter().map(|stop| stop.id).collect()
+}
+
+#[rstest]
+fn giant_tour_concatenates_routes(stops: Vec<Stop>, route_factory: RouteFactory) {
+    let route1 = route_factory(vec![stops[0], stops[2], stops[1], stops[0]]);
+    let route2 = route_factory(vec![stops[0], stops[0]]);
+    let route3 = route_factory(vec![stops[0], stops[3], stops[0]]);
+
+    let individual = Individual::new(vec![route1, route2, route3]);
+
+    assert_eq!(ids(&individual.giant_tour()), vec![2, 1, 3]);
+}
+
+#[rstest]
+fn order_crossover_keeps_permutation() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let tour1: Vec<Stop> = (1..9).map(|id| Stop::new(id, 0)).collect();
+    let tour2: Vec<Stop> = tour1.iter().rev().copied().collect();
+
+    for _ in 0..20 {
+        let offspring = order_crossover(&tour1, &tour2, &mut rng);
+        let genes: HashSet<u32> = offspring.iter().map(|stop| stop.id).collect();
+
+        assert_eq!(offspring.len(), tour1.len());
+        assert_eq!(genes.len(), tour1.len());
+    }
+}
+
+#[rstest]
+fn inversion_mutation_keeps_permutation() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let mut tour: Vec<Stop> = (1..9).map(|id| Stop::new(id, 0)).collect();
+
+    inversion_mutation(&mut tour, &mut rng);
+
+    let mut sorted = ids(&tour);
+    sorted.sort();
+
+    assert_eq!(sorted, (1..9).collect::<Vec<u32>>());
+}
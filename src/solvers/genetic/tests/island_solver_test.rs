@@ -0,0 +1,69 @@
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceMatrix,
+    solvers::genetic::island_solver::{IslandSolver, MigrationTopology},
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+#[rstest]
+#[case(MigrationTopology::Ring)]
+#[case(MigrationTopology::FullyConnected)]
+fn test_island_solver_can_generate_a_good_route(
+    #[case] topology: MigrationTopology,
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut solver = IslandSolver::new(
+        stops,
+        vehicle_factory(2),
+        &distances,
+        3,
+        10,
+        3,
+        0.05,
+        10,
+        6,
+        0,
+    )
+    .with_migration(topology, 2, 2);
+
+    solver.solve();
+
+    let solution_v1 = solver.solution.result.get(&0).unwrap();
+    let solution_v2 = solver.solution.result.get(&1).unwrap();
+
+    assert_ne!(solution_v1.len(), 0);
+    assert_ne!(solution_v2.len(), 0);
+    assert!(solver.solution.total_distance < f64::MAX);
+}
+
+#[rstest]
+fn test_single_island_behaves_like_genetic_solver(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut solver = IslandSolver::new(
+        stops,
+        vehicle_factory(2),
+        &distances,
+        1,
+        10,
+        3,
+        0.05,
+        10,
+        5,
+        0,
+    );
+
+    solver.solve();
+
+    assert!(solver.solution.result.contains_key(&0));
+    assert!(solver.solution.result.contains_key(&1));
+}
@@ -1,7 +1,27 @@
+#[cfg(test)]
+mod diversity_test;
+#[cfg(test)]
+mod education_test;
 pub(crate) mod fixtures;
 #[cfg(test)]
 mod genetic_algorithm_test;
 #[cfg(test)]
+mod giant_tour_solver_test;
+#[cfg(test)]
+mod giant_tour_test;
+#[cfg(test)]
 mod individual_test;
 #[cfg(test)]
+mod island_solver_test;
+#[cfg(test)]
+mod mutation_test;
+#[cfg(test)]
+mod penalty_test;
+#[cfg(test)]
 mod population_test;
+#[cfg(test)]
+mod selection_test;
+#[cfg(test)]
+mod split_test;
+#[cfg(test)]
+mod stopping_criteria_test;
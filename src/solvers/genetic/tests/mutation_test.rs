@@ -0,0 +1,212 @@
+use std::sync::Arc;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceMatrix,
+    solvers::genetic::{
+        crossover::crossover_kind::CrossoverKind,
+        genetic_solver::GeneticSolver,
+        individual::Individual,
+        mutation::{Mutation, MutationKind, MutationSchedule},
+        split::Split,
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::services_fixture::{route_service_factory, RouteServiceFactory};
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+fn individual(split: &Split, stops: &[Stop], routes: [&[usize]; 2]) -> Individual {
+    let chromosomes = routes
+        .iter()
+        .zip(vehicle_factory()(2))
+        .map(|(route, vehicle)| {
+            let genes: Vec<Stop> = route.iter().map(|index| stops[*index]).collect();
+            split.build_route(vehicle, &genes).unwrap()
+        })
+        .collect();
+
+    Individual::new(chromosomes)
+}
+
+fn actual_distance(distances: &DistanceMatrix, individual: &Individual) -> f64 {
+    individual
+        .chromosomes
+        .iter()
+        .flat_map(|chromosome| chromosome.stops.windows(2))
+        .map(|pair| {
+            distances
+                .get(&(pair[0].id, pair[1].id))
+                .copied()
+                .unwrap_or(0.0)
+        })
+        .sum()
+}
+
+fn customer_ids(individual: &Individual) -> Vec<u32> {
+    let mut ids: Vec<u32> = individual
+        .chromosomes
+        .iter()
+        .flat_map(|chromosome| chromosome.stops.iter().map(|stop| stop.id))
+        .filter(|id| *id != 0)
+        .collect();
+
+    ids.sort();
+    ids
+}
+
+#[rstest]
+#[case(MutationKind::Swap)]
+#[case(MutationKind::Inversion)]
+#[case(MutationKind::Insertion)]
+#[case(MutationKind::InterRoute)]
+#[case(MutationKind::Scramble)]
+fn mutation_keeps_customers_and_fitness_consistent(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+    #[case] kind: MutationKind,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let split = Arc::new(Split::new(vehicle_factory(2), &distances, stops.clone()));
+    let mutation = Mutation::new(1.0, split.clone(), vec![(kind, 1.0)]);
+
+    for _ in 0..10 {
+        let mut individual = individual(&split, &stops, [&[1, 2, 3], &[]]);
+
+        mutation.apply(kind, &mut individual, &mut rng).unwrap();
+
+        assert_eq!(customer_ids(&individual), vec![1, 2, 3]);
+        assert_eq!(individual.fitness, actual_distance(&distances, &individual));
+    }
+}
+
+#[rstest]
+fn inter_route_mutation_moves_a_customer(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let split = Arc::new(Split::new(vehicle_factory(2), &distances, stops.clone()));
+    let mutation = Mutation::new(1.0, split.clone(), vec![(MutationKind::InterRoute, 1.0)]);
+
+    let mut individual = individual(&split, &stops, [&[1, 2, 3], &[]]);
+    mutation
+        .apply(MutationKind::InterRoute, &mut individual, &mut rng)
+        .unwrap();
+
+    assert_eq!(individual.chromosomes[0].stops.len(), 4);
+    assert_eq!(individual.chromosomes[1].stops.len(), 3);
+}
+
+#[rstest]
+fn intra_route_mutation_needs_two_customers(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let split = Arc::new(Split::new(vehicle_factory(2), &distances, stops.clone()));
+    let mutation = Mutation::new(1.0, split.clone(), vec![(MutationKind::Swap, 1.0)]);
+
+    let mut individual = individual(&split, &stops, [&[1], &[2]]);
+
+    assert!(mutation
+        .apply(MutationKind::Swap, &mut individual, &mut rng)
+        .is_none());
+}
+
+#[rstest]
+fn linear_schedule_interpolates_rate(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let split = Arc::new(Split::new(vehicle_factory(2), &distances, stops));
+    let mut mutation = Mutation::new(0.5, split, vec![(MutationKind::Swap, 1.0)]);
+    mutation.set_schedule(MutationSchedule::Linear { final_rate: 0.1 });
+
+    mutation.update(5, 10);
+    assert!((mutation.rate - 0.3).abs() < 1e-9);
+
+    mutation.update(10, 10);
+    assert!((mutation.rate - 0.1).abs() < 1e-9);
+}
+
+#[rstest]
+fn self_adaptive_schedule_lowers_rate_without_success(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let split = Arc::new(Split::new(vehicle_factory(2), &distances, stops.clone()));
+    let mut mutation = Mutation::new(
+        0.5,
+        split.clone(),
+        vec![(MutationKind::Swap, 1.0), (MutationKind::InterRoute, 1.0)],
+    );
+    mutation.set_schedule(MutationSchedule::SelfAdaptive);
+
+    let mut optimal = individual(&split, &stops, [&[2, 3, 1], &[]]);
+    mutation.weights = vec![(MutationKind::Swap, 1.0), (MutationKind::InterRoute, 0.0)];
+    mutation.mutate(&mut optimal, &mut rng);
+    mutation.update(1, 10);
+
+    assert!(mutation.rate < 0.5);
+    assert!(mutation.weights[0].1 < 1.0);
+    assert_eq!(mutation.weights[1].1, 0.0);
+}
+
+#[rstest]
+#[case(MutationSchedule::Constant)]
+#[case(MutationSchedule::Linear { final_rate: 0.0 })]
+#[case(MutationSchedule::SelfAdaptive)]
+fn genetic_algorithm_with_mutation_operators_reports_best(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_service_factory: RouteServiceFactory,
+    #[case] schedule: MutationSchedule,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    let mut solver = GeneticSolver::new(
+        stops,
+        &distances,
+        10,
+        3,
+        0.5,
+        10,
+        20,
+        route_service_factory(2),
+        &mut rng,
+    )
+    .with_crossovers(vec![(CrossoverKind::PartiallyMapped, 1.0)])
+    .with_mutations(vec![
+        (MutationKind::Swap, 1.0),
+        (MutationKind::Inversion, 1.0),
+        (MutationKind::Insertion, 1.0),
+        (MutationKind::InterRoute, 1.0),
+        (MutationKind::Scramble, 1.0),
+    ])
+    .with_mutation_schedule(schedule);
+
+    solver.solve();
+
+    let best = solver
+        .solution
+        .result
+        .values()
+        .flat_map(|route| route.windows(2))
+        .map(|pair| distances.get(&(pair[0], pair[1])).copied().unwrap_or(0.0))
+        .sum::<f64>();
+
+    assert_eq!(solver.solution.total_distance, best);
+    assert_eq!(best, 8.0);
+}
@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    services::{distance::distance_service::DistanceMatrix, route::route_service::RouteService},
+    solvers::genetic::{
+        genetic_solver::GeneticSolver, individual::Individual, penalty::Penalty, repair::Repair,
+        split::Split,
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+fn loaded_stops() -> Vec<Stop> {
+    (0..5).map(|id| Stop::new(id, 4 * id.min(1))).collect()
+}
+
+fn forced_route(distances: &DistanceMatrix, vehicle: Vehicle, stops: &[Stop]) -> Route {
+    let mut route = Route::new(vehicle);
+    route.force_add_stop(stops[0], 0.0);
+
+    for pair in stops.windows(2) {
+        route.force_add_stop(pair[1], distances[&(pair[0].id, pair[1].id)]);
+    }
+
+    route
+}
+
+fn overloaded_individual(distances: &DistanceMatrix, stops: &[Stop]) -> Individual {
+    let route1 = forced_route(
+        distances,
+        Vehicle::new(0, 10),
+        &[stops[0], stops[1], stops[3], stops[2], stops[0]],
+    );
+    let route2 = forced_route(
+        distances,
+        Vehicle::new(1, 10),
+        &[stops[0], stops[4], stops[0]],
+    );
+
+    Individual::new(vec![route1, route2])
+}
+
+#[rstest]
+fn penalty_increases_when_offspring_are_infeasible() {
+    let mut penalty = Penalty::new(10.0);
+
+    (0..10).for_each(|_| penalty.record(false));
+
+    assert!(penalty.adapt());
+    assert!(penalty.value > 10.0);
+}
+
+#[rstest]
+fn penalty_decreases_when_offspring_are_feasible() {
+    let mut penalty = Penalty::new(10.0);
+
+    (0..10).for_each(|_| penalty.record(true));
+
+    assert!(penalty.adapt());
+    assert!(penalty.value < 10.0);
+}
+
+#[rstest]
+fn penalty_is_kept_without_offspring() {
+    let mut penalty = Penalty::new(10.0);
+
+    assert!(!penalty.adapt());
+    assert_eq!(penalty.value, 10.0);
+}
+
+#[rstest]
+fn infeasible_fitness_includes_penalty(distances: DistanceMatrix) {
+    let stops = loaded_stops();
+    let mut individual = overloaded_individual(&distances, &stops);
+
+    individual.set_penalty(2.0);
+
+    assert!(!individual.is_feasible());
+    assert_eq!(individual.excess_load(), 3);
+    assert_eq!(individual.fitness, 8.0 + 1.0 + 2.0 * 3.0);
+}
+
+#[rstest]
+fn repair_restores_feasibility(distances: DistanceMatrix, vehicle_factory: VehicleFactory) {
+    let stops = loaded_stops();
+    let split = Arc::new(Split::new(vehicle_factory(2), &distances, stops.clone()));
+    let repair = Repair::new(split);
+
+    let individual = overloaded_individual(&distances, &stops);
+    let repaired = repair.repair(&individual).unwrap();
+
+    let mut customers: Vec<u32> = repaired
+        .chromosomes
+        .iter()
+        .flat_map(|chromosome| chromosome.stops.iter().map(|stop| stop.id))
+        .filter(|id| *id != 0)
+        .collect();
+    customers.sort();
+
+    assert!(repaired.is_feasible());
+    assert_eq!(customers, vec![1, 2, 3, 4]);
+}
+
+#[rstest]
+fn repair_fails_without_fleet_capacity(distances: DistanceMatrix) {
+    let stops = loaded_stops();
+    let split = Arc::new(Split::new(
+        vec![Vehicle::new(0, 10)],
+        &distances,
+        stops.clone(),
+    ));
+    let repair = Repair::new(split);
+
+    let route = forced_route(
+        &distances,
+        Vehicle::new(0, 10),
+        &[stops[0], stops[1], stops[2], stops[3], stops[4], stops[0]],
+    );
+
+    assert!(repair.repair(&Individual::new(vec![route])).is_none());
+}
+
+#[rstest]
+fn genetic_algorithm_with_infeasible_individuals_reports_feasible_solution(
+    distances: DistanceMatrix,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let stops = loaded_stops();
+    let route_service = RouteService::new(vehicle_factory(2), &distances, stops.clone());
+
+    let mut solver = GeneticSolver::new(
+        stops.clone(),
+        &distances,
+        10,
+        3,
+        0.05,
+        10,
+        10,
+        route_service,
+        &mut rng,
+    )
+    .with_infeasible_individuals(1.0);
+
+    solver.solve();
+
+    let loads: Vec<u32> = solver
+        .solution
+        .result
+        .values()
+        .map(|route| route.iter().map(|id| stops[*id as usize].usage).sum())
+        .collect();
+
+    let mut customers: Vec<u32> = solver
+        .solution
+        .result
+        .values()
+        .flatten()
+        .copied()
+        .filter(|id| *id != 0)
+        .collect();
+    customers.sort();
+
+    assert_eq!(customers, vec![1, 2, 3, 4]);
+    assert!(loads.iter().all(|load| *load < 10));
+}
@@ -9,3 +9,21 @@ fn test_generate_random_population(mut population_factory: PopulationFactory) {
     assert_ne!(population.individuals[0].chromosomes[0].stops.len(), 0);
     assert_ne!(population.individuals[0].chromosomes[0].stops.len(), 0);
 }
+
+#[rstest]
+fn test_k_bests_are_sorted(mut population_factory: PopulationFactory) {
+    let population = population_factory(6, 2);
+    let k_bests = population.get_k_bests(3);
+
+    assert_eq!(k_bests.len(), 3);
+
+    for window in k_bests.windows(2) {
+        assert!(
+            population.individuals[window[0]].fitness <= population.individuals[window[1]].fitness
+        );
+    }
+
+    for individual in population.individuals.iter() {
+        assert!(population.best().unwrap().fitness <= individual.fitness);
+    }
+}
@@ -0,0 +1,67 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::{fixture, rstest};
+
+use crate::solvers::genetic::{individual::Individual, selection::SelectionStrategy};
+
+#[fixture]
+fn individuals() -> Vec<Individual> {
+    [7.0, 3.0, 9.0, 1.0, 5.0]
+        .iter()
+        .map(|fitness| {
+            let mut individual = Individual::new(vec![]);
+            individual.fitness = *fitness;
+            individual
+        })
+        .collect()
+}
+
+fn count_selections(
+    strategy: SelectionStrategy,
+    individuals: &[Individual],
+    index: usize,
+) -> usize {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    (0..1000)
+        .filter(|_| strategy.select(individuals, &mut rng) == Some(index))
+        .count()
+}
+
+#[rstest]
+fn tournament_prefers_lower_cost(individuals: Vec<Individual>) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let strategy = SelectionStrategy::Tournament { size: 50 };
+
+    assert_eq!(strategy.select(&individuals, &mut rng), Some(3));
+}
+
+#[rstest]
+fn rank_based_prefers_lower_cost(individuals: Vec<Individual>) {
+    let best = count_selections(SelectionStrategy::RankBased, &individuals, 3);
+    let worst = count_selections(SelectionStrategy::RankBased, &individuals, 2);
+
+    assert!(best > worst);
+}
+
+#[rstest]
+fn roulette_inverts_cost(individuals: Vec<Individual>) {
+    let best = count_selections(SelectionStrategy::Roulette, &individuals, 3);
+    let worst = count_selections(SelectionStrategy::Roulette, &individuals, 2);
+
+    assert!(
+        SelectionStrategy::inverted_cost(&individuals[3])
+            > SelectionStrategy::inverted_cost(&individuals[2])
+    );
+    assert!(best > worst);
+}
+
+#[rstest]
+fn selected_pair_is_distinct(individuals: Vec<Individual>) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let strategy = SelectionStrategy::Tournament { size: 50 };
+
+    let (parent1, parent2) = strategy.select_pair(&individuals, &mut rng, 3).unwrap();
+
+    assert_ne!(parent1, parent2);
+}
@@ -0,0 +1,85 @@
+use rstest::rstest;
+
+use crate::domain::stop::Stop;
+use crate::services::distance::distance_service::DistanceMatrix;
+use crate::solvers::genetic::split::Split;
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+fn route_ids(split: &Split, tour: &[Stop]) -> Vec<Vec<u32>> {
+    split
+        .run(tour)
+        .unwrap()
+        .iter()
+        .map(|route| route.stops.iter().map(|stop| stop.id).collect())
+        .collect()
+}
+
+#[rstest]
+fn split_ignores_unserviceable_stops(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let split = Split::new(vehicle_factory(2), &distances, stops);
+
+    let customer_ids: Vec<u32> = split.customers.iter().map(|stop| stop.id).collect();
+
+    assert_eq!(customer_ids, vec![1, 2, 3]);
+}
+
+#[rstest]
+fn split_finds_optimal_partition(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let split = Split::new(vehicle_factory(2), &distances, stops.clone());
+    let tour = vec![stops[2], stops[3], stops[1]];
+
+    let routes = split.run(&tour).unwrap();
+    let total: f64 = routes.iter().map(|route| route.total_distance()).sum();
+
+    assert_eq!(total, 8.0);
+    assert_eq!(
+        route_ids(&split, &tour),
+        vec![vec![0, 2, 3, 1, 0], vec![0, 0]]
+    );
+}
+
+#[rstest]
+fn split_respects_capacity(distances: DistanceMatrix, vehicle_factory: VehicleFactory) {
+    let stops: Vec<Stop> = (0..5).map(|id| Stop::new(id, 4 * id.min(1))).collect();
+    let split = Split::new(vehicle_factory(2), &distances, stops.clone());
+    let tour = vec![stops[1], stops[3], stops[2], stops[4]];
+
+    let routes = split.run(&tour).unwrap();
+    let total: f64 = routes.iter().map(|route| route.total_distance()).sum();
+
+    assert_eq!(total, 11.5);
+    assert_eq!(
+        route_ids(&split, &tour),
+        vec![vec![0, 1, 3, 0], vec![0, 2, 4, 0]]
+    );
+}
+
+#[rstest]
+fn split_fails_without_enough_vehicles(distances: DistanceMatrix, vehicle_factory: VehicleFactory) {
+    let stops: Vec<Stop> = (0..5).map(|id| Stop::new(id, 6 * id.min(1))).collect();
+    let split = Split::new(vehicle_factory(2), &distances, stops.clone());
+
+    assert!(split.run(&stops[1..]).is_none());
+}
+
+#[rstest]
+fn split_fails_without_depot(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let split = Split::new(vehicle_factory(2), &distances, stops[1..].to_vec());
+
+    assert!(split.run(&stops[1..4]).is_none());
+}
@@ -0,0 +1,151 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceMatrix,
+    solvers::genetic::{genetic_solver::GeneticSolver, stopping_criteria::StoppingCriteria},
+};
+
+use crate::solvers::genetic::tests::fixtures::{population_factory, PopulationFactory};
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::services_fixture::{route_service_factory, RouteServiceFactory};
+use crate::tests::fixtures::stops_fixture::stops;
+
+#[rstest]
+fn default_criteria_are_never_met() {
+    let criteria = StoppingCriteria::default();
+
+    assert!(!criteria.is_met(u32::MAX, 0.0, || 0.0));
+}
+
+#[rstest]
+#[case(StoppingCriteria { max_generations_without_improvement: Some(5), ..Default::default() }, 5, 10.0, 1.0, true)]
+#[case(StoppingCriteria { max_generations_without_improvement: Some(5), ..Default::default() }, 4, 10.0, 1.0, false)]
+#[case(StoppingCriteria { target_fitness: Some(8.0), ..Default::default() }, 0, 8.0, 1.0, true)]
+#[case(StoppingCriteria { target_fitness: Some(8.0), ..Default::default() }, 0, 9.0, 1.0, false)]
+#[case(StoppingCriteria { min_diversity: Some(0.1), ..Default::default() }, 0, 10.0, 0.05, true)]
+#[case(StoppingCriteria { min_diversity: Some(0.1), ..Default::default() }, 0, 10.0, 0.5, false)]
+fn criteria_are_checked(
+    #[case] criteria: StoppingCriteria,
+    #[case] generations_without_improvement: u32,
+    #[case] best_fitness: f64,
+    #[case] diversity: f64,
+    #[case] expected: bool,
+) {
+    assert_eq!(
+        criteria.is_met(generations_without_improvement, best_fitness, || diversity),
+        expected
+    );
+}
+
+#[rstest]
+fn population_diversity_is_average_distance(mut population_factory: PopulationFactory) {
+    let population = population_factory(5, 2);
+    let diversity = population.diversity();
+
+    assert!((0.0..=1.0).contains(&diversity));
+    assert_eq!(population_factory(1, 2).diversity(), 0.0);
+}
+
+#[rstest]
+fn genetic_algorithm_stops_at_target(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_service_factory: RouteServiceFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    let mut solver = GeneticSolver::new(
+        stops,
+        &distances,
+        10,
+        3,
+        0.05,
+        10,
+        100,
+        route_service_factory(2),
+        &mut rng,
+    )
+    .with_stopping_criteria(StoppingCriteria {
+        target_fitness: Some(f64::MAX / 2.0),
+        ..Default::default()
+    });
+
+    solver.solve();
+
+    assert_eq!(solver.generations(), 1);
+}
+
+#[rstest]
+fn genetic_algorithm_stops_on_stagnation(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_service_factory: RouteServiceFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    let mut solver = GeneticSolver::new(
+        stops,
+        &distances,
+        10,
+        3,
+        0.05,
+        10,
+        1000,
+        route_service_factory(2),
+        &mut rng,
+    )
+    .with_stopping_criteria(StoppingCriteria {
+        max_generations_without_improvement: Some(5),
+        ..Default::default()
+    });
+
+    solver.solve();
+
+    assert!(solver.generations() < 1000);
+    assert_ne!(solver.solution.total_distance, f64::MAX);
+}
+
+#[rstest]
+fn genetic_algorithm_with_restarts_keeps_best(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_service_factory: RouteServiceFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    let mut solver = GeneticSolver::new(
+        stops.clone(),
+        &distances,
+        10,
+        3,
+        0.05,
+        10,
+        30,
+        route_service_factory(2),
+        &mut rng,
+    )
+    .with_restart(3);
+
+    solver.solve();
+
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let mut without_restart = GeneticSolver::new(
+        stops,
+        &distances,
+        10,
+        3,
+        0.05,
+        10,
+        3,
+        route_service_factory(2),
+        &mut rng,
+    );
+
+    without_restart.solve();
+
+    assert_eq!(solver.generations(), 30);
+    assert!(solver.solution.total_distance <= without_restart.solution.total_distance);
+}
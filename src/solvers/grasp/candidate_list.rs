@@ -0,0 +1,50 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::domain::stop::Stop;
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum CandidateList {
+    #[default]
+    Cardinality,
+    Value {
+        alpha: f64,
+    },
+}
+
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+pub enum CandidateBias {
+    #[default]
+    Uniform,
+    Rank,
+    Exponential,
+}
+
+impl CandidateBias {
+    pub(crate) fn weight(&self, rank: usize) -> f64 {
+        match self {
+            Self::Uniform => 1.0,
+            Self::Rank => 1.0 / rank as f64,
+            Self::Exponential => (-(rank as f64)).exp(),
+        }
+    }
+
+    pub(crate) fn choose<'a, R>(&self, candidates: &[&'a Stop], rng: &mut R) -> Option<&'a Stop>
+    where
+        R: Rng + ?Sized,
+    {
+        if *self == Self::Uniform {
+            return candidates.choose(rng).copied();
+        }
+
+        let ranked: Vec<(usize, &Stop)> = candidates
+            .iter()
+            .enumerate()
+            .map(|(index, stop)| (index + 1, *stop))
+            .collect();
+
+        ranked
+            .choose_weighted(rng, |(rank, _)| self.weight(*rank))
+            .ok()
+            .map(|(_, stop)| *stop)
+    }
+}
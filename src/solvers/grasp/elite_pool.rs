@@ -0,0 +1,66 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::domain::route::Route;
+
+pub(crate) type EliteSolution = Vec<Route>;
+
+#[derive(Default)]
+pub(crate) struct ElitePool {
+    capacity: usize,
+    pub(crate) solutions: Vec<(EliteSolution, f64)>,
+}
+
+impl ElitePool {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            solutions: Vec::new(),
+        }
+    }
+
+    fn stop_ids(routes: &EliteSolution) -> Vec<Vec<u32>> {
+        routes
+            .iter()
+            .map(|route| route.stops.iter().map(|stop| stop.id).collect())
+            .collect()
+    }
+
+    fn contains(&self, routes: &EliteSolution) -> bool {
+        let stop_ids = Self::stop_ids(routes);
+
+        self.solutions
+            .iter()
+            .any(|(solution, _)| Self::stop_ids(solution) == stop_ids)
+    }
+
+    pub(crate) fn insert(&mut self, routes: EliteSolution, cost: f64) -> bool {
+        if self.capacity == 0 || self.contains(&routes) {
+            return false;
+        }
+
+        if self.solutions.len() >= self.capacity {
+            match self.solutions.last() {
+                Some((_, worst_cost)) if cost < *worst_cost => {
+                    self.solutions.pop();
+                }
+                _ => return false,
+            }
+        }
+
+        let index = self
+            .solutions
+            .iter()
+            .position(|(_, elite_cost)| cost < *elite_cost)
+            .unwrap_or(self.solutions.len());
+
+        self.solutions.insert(index, (routes, cost));
+        true
+    }
+
+    pub(crate) fn choose<R>(&self, rng: &mut R) -> Option<&EliteSolution>
+    where
+        R: Rng + ?Sized,
+    {
+        self.solutions.choose(rng).map(|(routes, _)| routes)
+    }
+}
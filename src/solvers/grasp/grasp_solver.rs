@@ -1,42 +1,119 @@
-use rand::{seq::SliceRandom, Rng};
+use rand::Rng;
 
 use crate::{
-    domain::{stop::Stop, vehicle::Vehicle},
-    local_search::two_opt::TwoOptSearcher,
-    services::{distance::distance_service::DistanceMatrix, route::route_service::RouteService},
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    errors::solver::invalid_solution::InvalidSolutionError,
+    local_search::{
+        improvement_strategy::ImprovementStrategy,
+        inter_route::inter_route_searcher::InterRouteSearcher, two_opt::TwoOptSearcher,
+    },
+    services::{distance::distance_oracle::IntoDistanceOracle, route::route_service::RouteService},
     solvers::solution::Solution,
 };
 
+use super::{
+    candidate_list::{CandidateBias, CandidateList},
+    elite_pool::ElitePool,
+    path_relinking::PathRelinking,
+    reactive_rcl::ReactiveRcl,
+};
+
+const INTER_ROUTE_SEGMENT_LENGTH: usize = 3;
+
 pub struct GraspSolver<'a, R: Rng + ?Sized> {
     rng: &'a mut R,
     rcl_size: usize,
+    candidate_list: CandidateList,
+    candidate_bias: CandidateBias,
     pub solution: Solution,
     local_search: TwoOptSearcher,
+    inter_route_search: InterRouteSearcher,
     route_service: RouteService,
     max_improvement_times: u8,
     times_without_improvement: u8,
+    reactive_rcl: Option<ReactiveRcl>,
+    elite_pool: ElitePool,
+    path_relinking: PathRelinking,
 }
 
 impl<'a, R: Rng + ?Sized> GraspSolver<'a, R> {
     pub fn new(
         rcl_size: usize,
         vehicles: Vec<Vehicle>,
-        distances: &'a DistanceMatrix,
+        distances: impl IntoDistanceOracle,
         max_improvement_times: u8,
         stops: Vec<Stop>,
         rng: &'a mut R,
     ) -> Self {
+        let oracle = distances.into_oracle();
+
         Self {
             rng,
             rcl_size,
             max_improvement_times,
+            candidate_list: Default::default(),
+            candidate_bias: Default::default(),
             solution: Solution::default(),
             times_without_improvement: Default::default(),
-            local_search: TwoOptSearcher::new(stops.clone(), distances),
-            route_service: RouteService::new(vehicles, distances, stops),
+            local_search: TwoOptSearcher::new(
+                stops.clone(),
+                &oracle,
+                ImprovementStrategy::BestImprovement,
+                None,
+            ),
+            inter_route_search: InterRouteSearcher::new(
+                stops.clone(),
+                &oracle,
+                INTER_ROUTE_SEGMENT_LENGTH,
+                None,
+            ),
+            reactive_rcl: None,
+            elite_pool: ElitePool::default(),
+            path_relinking: PathRelinking::new(stops.clone(), &oracle),
+            route_service: RouteService::new(vehicles, &oracle, stops),
         }
     }
 
+    pub fn with_initial_solution(
+        mut self,
+        solution: &Solution,
+    ) -> Result<Self, InvalidSolutionError> {
+        self.route_service.load_solution(solution)?;
+
+        let initial_solution = Solution::new(
+            self.route_service.get_all_routes(),
+            self.route_service.total_distance(),
+        );
+        self.elite_pool
+            .insert(self.current_routes(), initial_solution.total_distance);
+        self.solution = initial_solution;
+
+        Ok(self)
+    }
+
+    pub fn with_candidate_list(mut self, candidate_list: CandidateList) -> Self {
+        self.candidate_list = candidate_list;
+        self
+    }
+
+    pub fn with_candidate_bias(mut self, candidate_bias: CandidateBias) -> Self {
+        self.candidate_bias = candidate_bias;
+        self
+    }
+
+    pub fn with_reactive_rcl(mut self, rcl_sizes: Vec<usize>, block_size: u32) -> Self {
+        self.reactive_rcl = match rcl_sizes.is_empty() {
+            true => None,
+            false => Some(ReactiveRcl::new(rcl_sizes, block_size)),
+        };
+        self
+    }
+
+    pub fn with_path_relinking(mut self, elite_size: usize) -> Self {
+        self.elite_pool = ElitePool::new(elite_size);
+        self
+    }
+
     pub fn solve(&mut self) {
         while !self.stop_condition_met() {
             self.run_generation();
@@ -47,7 +124,7 @@ impl<'a, R: Rng + ?Sized> GraspSolver<'a, R> {
         self.times_without_improvement >= self.max_improvement_times
     }
 
-    fn run_generation(&mut self) {
+    pub(crate) fn construct_and_improve(&mut self) -> Solution {
         let vehicle_ids: Vec<u32> = self
             .route_service
             .get_all_routes()
@@ -58,10 +135,72 @@ impl<'a, R: Rng + ?Sized> GraspSolver<'a, R> {
         self.generate_solution(&vehicle_ids);
         self.run_local_search(&vehicle_ids);
 
-        let solution = Solution::new(
+        Solution::new(
+            self.route_service.get_all_routes(),
+            self.route_service.total_distance(),
+        )
+    }
+
+    fn choose_rcl_size(&mut self) -> Option<usize> {
+        let reactive_rcl = self.reactive_rcl.as_ref()?;
+        let index = reactive_rcl.choose(self.rng)?;
+
+        self.rcl_size = reactive_rcl.sizes[index];
+        Some(index)
+    }
+
+    fn record_rcl_size(&mut self, index: Option<usize>, cost: f64) {
+        let best_cost = cost.min(self.solution.total_distance);
+
+        if let (Some(reactive_rcl), Some(index)) = (self.reactive_rcl.as_mut(), index) {
+            reactive_rcl.record(index, cost, best_cost);
+        }
+    }
+
+    fn current_routes(&self) -> Vec<Route> {
+        self.route_service
+            .get_all_routes()
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    fn relink_with_elite(&mut self, solution: Solution) -> Solution {
+        let routes = self.current_routes();
+        let relinked = self
+            .elite_pool
+            .choose(self.rng)
+            .and_then(|guiding| self.path_relinking.relink(&routes, guiding));
+
+        self.elite_pool.insert(routes, solution.total_distance);
+
+        let Some(relinked) = relinked else {
+            return solution;
+        };
+
+        let vehicle_ids: Vec<u32> = relinked.iter().map(|route| route.vehicle.id).collect();
+        self.route_service.update_routes(relinked);
+        self.run_local_search(&vehicle_ids);
+
+        let relinked_solution = Solution::new(
             self.route_service.get_all_routes(),
             self.route_service.total_distance(),
         );
+        self.elite_pool
+            .insert(self.current_routes(), relinked_solution.total_distance);
+
+        match relinked_solution.is_better_than(&solution) {
+            true => relinked_solution,
+            false => solution,
+        }
+    }
+
+    fn run_generation(&mut self) {
+        let rcl_index = self.choose_rcl_size();
+        let solution = self.construct_and_improve();
+        self.record_rcl_size(rcl_index, solution.total_distance);
+
+        let solution = self.relink_with_elite(solution);
 
         if self.should_update_solution(&solution) {
             self.solution = solution;
@@ -99,12 +238,16 @@ impl<'a, R: Rng + ?Sized> GraspSolver<'a, R> {
     }
 
     pub fn get_random_near_stop(&mut self, vehicle_id: u32) -> Option<&Stop> {
-        let near_stops = self
-            .route_service
-            .get_k_nearest_stops(vehicle_id, self.rcl_size)?;
-        let chosen = *near_stops.choose(self.rng)?;
+        let near_stops = match self.candidate_list {
+            CandidateList::Cardinality => self
+                .route_service
+                .get_k_nearest_stops(vehicle_id, self.rcl_size)?,
+            CandidateList::Value { alpha } => self
+                .route_service
+                .get_alpha_nearest_stops(vehicle_id, alpha)?,
+        };
 
-        Some(chosen)
+        self.candidate_bias.choose(&near_stops, self.rng)
     }
 
     fn run_local_search(&mut self, vehicle_ids: &Vec<u32>) {
@@ -113,6 +256,16 @@ impl<'a, R: Rng + ?Sized> GraspSolver<'a, R> {
 
             self.local_search.run(route);
         }
+
+        let mut routes: Vec<Route> = self
+            .route_service
+            .get_all_routes()
+            .values()
+            .cloned()
+            .collect();
+
+        self.inter_route_search.run(&mut routes);
+        self.route_service.update_routes(routes);
     }
 
     fn should_update_solution(&self, solution: &Solution) -> bool {
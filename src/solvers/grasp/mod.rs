@@ -1,2 +1,8 @@
+pub mod candidate_list;
+mod elite_pool;
+mod path_relinking;
+mod reactive_rcl;
+
 pub mod grasp_solver;
+pub mod parallel_grasp_solver;
 mod tests;
@@ -0,0 +1,127 @@
+use std::thread;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    services::distance::distance_oracle::{IntoDistanceOracle, SharedDistanceOracle},
+    solvers::solution::Solution,
+};
+
+use super::{
+    candidate_list::{CandidateBias, CandidateList},
+    grasp_solver::GraspSolver,
+};
+
+pub struct ParallelGraspSolver {
+    rcl_size: usize,
+    candidate_list: CandidateList,
+    candidate_bias: CandidateBias,
+    stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: SharedDistanceOracle,
+    max_improvement_times: u8,
+    number_of_workers: usize,
+    seed: u64,
+    pub solution: Solution,
+}
+
+impl ParallelGraspSolver {
+    pub fn new(
+        rcl_size: usize,
+        vehicles: Vec<Vehicle>,
+        distances: impl IntoDistanceOracle,
+        max_improvement_times: u8,
+        stops: Vec<Stop>,
+        number_of_workers: usize,
+        seed: u64,
+    ) -> Self {
+        Self {
+            seed,
+            stops,
+            rcl_size,
+            vehicles,
+            candidate_list: Default::default(),
+            candidate_bias: Default::default(),
+            max_improvement_times,
+            distances: distances.into_oracle(),
+            number_of_workers: number_of_workers.max(1),
+            solution: Default::default(),
+        }
+    }
+
+    pub fn with_candidate_list(mut self, candidate_list: CandidateList) -> Self {
+        self.candidate_list = candidate_list;
+        self
+    }
+
+    pub fn with_candidate_bias(mut self, candidate_bias: CandidateBias) -> Self {
+        self.candidate_bias = candidate_bias;
+        self
+    }
+
+    fn worker_rngs(&self) -> Vec<ChaCha8Rng> {
+        (0..self.number_of_workers)
+            .map(|worker| {
+                let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+                rng.set_stream(worker as u64);
+                rng
+            })
+            .collect()
+    }
+
+    fn create_worker<'a>(&'a self, rng: &'a mut ChaCha8Rng) -> GraspSolver<'a, ChaCha8Rng> {
+        GraspSolver::new(
+            self.rcl_size,
+            self.vehicles.clone(),
+            &self.distances,
+            self.max_improvement_times,
+            self.stops.clone(),
+            rng,
+        )
+        .with_candidate_list(self.candidate_list)
+        .with_candidate_bias(self.candidate_bias)
+    }
+
+    fn run_round(workers: &mut [GraspSolver<ChaCha8Rng>]) -> Vec<Solution> {
+        thread::scope(|scope| {
+            let handles: Vec<_> = workers
+                .iter_mut()
+                .map(|worker| scope.spawn(move || worker.construct_and_improve()))
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        })
+    }
+
+    pub fn solve(&mut self) {
+        let mut rngs = self.worker_rngs();
+        let mut workers: Vec<GraspSolver<ChaCha8Rng>> =
+            rngs.iter_mut().map(|rng| self.create_worker(rng)).collect();
+
+        let mut incumbent = Solution::default();
+        let mut rounds_without_improvement = 0;
+
+        while rounds_without_improvement < self.max_improvement_times {
+            let mut improved = false;
+
+            for solution in Self::run_round(&mut workers) {
+                if solution.is_better_than(&incumbent) {
+                    incumbent = solution;
+                    improved = true;
+                }
+            }
+
+            rounds_without_improvement = match improved {
+                true => 0,
+                false => rounds_without_improvement + 1,
+            };
+        }
+
+        self.solution = incumbent;
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    services::distance::{distance_oracle::IntoDistanceOracle, distance_service::DistanceService},
+};
+
+type Customers = Vec<Vec<Stop>>;
+
+pub(crate) struct PathRelinking {
+    distance_service: DistanceService,
+}
+
+impl PathRelinking {
+    pub(crate) fn new(stops: Vec<Stop>, distances: impl IntoDistanceOracle) -> Self {
+        Self {
+            distance_service: DistanceService::new(stops, distances),
+        }
+    }
+
+    fn customers(routes: &[Route]) -> Customers {
+        routes.iter().map(Route::customers).collect()
+    }
+
+    fn build_route(&self, vehicle: &Vehicle, depot: Stop, customers: &[Stop]) -> Option<Route> {
+        let mut route = Route::new(Vehicle::new(vehicle.id, vehicle.capacity));
+        route.add_stop(depot, 0.0).ok()?;
+
+        for stop in customers.iter().chain(std::iter::once(&depot)) {
+            let distance = self
+                .distance_service
+                .get_distance(route.get_current_stop()?, stop)?;
+
+            route.add_stop(*stop, distance).ok()?;
+        }
+
+        Some(route)
+    }
+
+    fn build_routes(
+        &self,
+        vehicles: &[Vehicle],
+        depot: Stop,
+        customers: &Customers,
+    ) -> Option<Vec<Route>> {
+        vehicles
+            .iter()
+            .zip(customers)
+            .map(|(vehicle, stops)| self.build_route(vehicle, depot, stops))
+            .collect()
+    }
+
+    fn apply_move(
+        current: &Customers,
+        vehicle_index: usize,
+        position: usize,
+        stop: Stop,
+    ) -> Customers {
+        let mut customers = current.clone();
+
+        for stops in customers.iter_mut() {
+            stops.retain(|customer| customer.id != stop.id);
+        }
+
+        let position = position.min(customers[vehicle_index].len());
+        customers[vehicle_index].insert(position, stop);
+
+        customers
+    }
+
+    fn candidate_moves(current: &Customers, target: &Customers) -> Vec<Customers> {
+        target
+            .iter()
+            .enumerate()
+            .filter_map(|(vehicle_index, stops)| {
+                let position = stops.iter().enumerate().position(|(index, stop)| {
+                    current[vehicle_index]
+                        .get(index)
+                        .map(|customer| customer.id)
+                        != Some(stop.id)
+                })?;
+
+                Some(Self::apply_move(
+                    current,
+                    vehicle_index,
+                    position,
+                    stops[position],
+                ))
+            })
+            .collect()
+    }
+
+    fn total_distance(routes: &[Route]) -> f64 {
+        routes.iter().map(|route| route.total_distance()).sum()
+    }
+
+    pub(crate) fn relink(&self, initial: &[Route], guiding: &[Route]) -> Option<Vec<Route>> {
+        if initial.len() != guiding.len() {
+            return None;
+        }
+
+        let depot = *initial.first()?.stops.first()?;
+        let vehicles: Vec<Vehicle> = initial.iter().map(|route| route.vehicle).collect();
+        let target = Self::customers(guiding);
+        let mut current = Self::customers(initial);
+        let mut best: Option<Vec<Route>> = None;
+
+        loop {
+            let candidates = Self::candidate_moves(&current, &target);
+
+            if candidates.is_empty() {
+                break;
+            }
+
+            let evaluated: Vec<(Customers, Option<Vec<Route>>)> = candidates
+                .into_iter()
+                .map(|customers| {
+                    let routes = self.build_routes(&vehicles, depot, &customers);
+                    (customers, routes)
+                })
+                .collect();
+
+            let chosen = evaluated
+                .iter()
+                .enumerate()
+                .filter_map(|(index, (_, routes))| {
+                    Some((index, Self::total_distance(routes.as_ref()?)))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map_or(0, |(index, _)| index);
+
+            let (customers, routes) = evaluated.into_iter().nth(chosen)?;
+            current = customers;
+
+            if current == target {
+                break;
+            }
+
+            if let Some(routes) = routes {
+                let is_better = best.as_ref().map_or(true, |best| {
+                    Self::total_distance(&routes) < Self::total_distance(best)
+                });
+
+                if is_better {
+                    best = Some(routes);
+                }
+            }
+        }
+
+        best
+    }
+}
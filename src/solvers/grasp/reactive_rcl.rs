@@ -0,0 +1,75 @@
+use rand::{seq::SliceRandom, Rng};
+
+const AMPLIFICATION: f64 = 10.0;
+
+pub(crate) struct ReactiveRcl {
+    pub(crate) sizes: Vec<usize>,
+    pub(crate) probabilities: Vec<f64>,
+    costs: Vec<(f64, u32)>,
+    block_size: u32,
+    iterations: u32,
+}
+
+impl ReactiveRcl {
+    pub(crate) fn new(sizes: Vec<usize>, block_size: u32) -> Self {
+        let probability = 1.0 / sizes.len().max(1) as f64;
+
+        Self {
+            block_size: block_size.max(1),
+            probabilities: vec![probability; sizes.len()],
+            costs: vec![(0.0, 0); sizes.len()],
+            iterations: Default::default(),
+            sizes,
+        }
+    }
+
+    pub(crate) fn choose<R>(&self, rng: &mut R) -> Option<usize>
+    where
+        R: Rng + ?Sized,
+    {
+        let indexes: Vec<usize> = (0..self.sizes.len()).collect();
+
+        indexes
+            .choose_weighted(rng, |index| self.probabilities[*index])
+            .ok()
+            .copied()
+    }
+
+    pub(crate) fn record(&mut self, index: usize, cost: f64, best_cost: f64) {
+        let (total, count) = &mut self.costs[index];
+        *total += cost;
+        *count += 1;
+
+        self.iterations += 1;
+
+        if self.iterations % self.block_size == 0 {
+            self.update_probabilities(best_cost);
+        }
+    }
+
+    fn update_probabilities(&mut self, best_cost: f64) {
+        let qualities: Vec<f64> = self
+            .costs
+            .iter()
+            .map(|(total, count)| match *count {
+                0 => 1.0,
+                _ => {
+                    let average = total / *count as f64;
+
+                    match average > 0.0 {
+                        true => (best_cost / average).powf(AMPLIFICATION),
+                        false => 1.0,
+                    }
+                }
+            })
+            .collect();
+
+        let total: f64 = qualities.iter().sum();
+
+        if total <= 0.0 || !total.is_finite() {
+            return;
+        }
+
+        self.probabilities = qualities.iter().map(|quality| quality / total).collect();
+    }
+}
@@ -0,0 +1,44 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{domain::stop::Stop, solvers::grasp::candidate_list::CandidateBias};
+
+#[rstest]
+#[case(CandidateBias::Uniform)]
+#[case(CandidateBias::Rank)]
+#[case(CandidateBias::Exponential)]
+fn bias_weights_do_not_increase_with_rank(#[case] bias: CandidateBias) {
+    let weights: Vec<f64> = (1..=5).map(|rank| bias.weight(rank)).collect();
+
+    assert!(weights.windows(2).all(|pair| pair[0] >= pair[1]));
+    assert!(weights.iter().all(|weight| *weight > 0.0));
+}
+
+#[rstest]
+fn biased_choice_favours_best_candidates() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let stops = [Stop::new(1, 0), Stop::new(2, 0), Stop::new(3, 0)];
+    let candidates: Vec<&Stop> = stops.iter().collect();
+
+    let mut counts = [0; 3];
+    for _ in 0..1000 {
+        let stop = CandidateBias::Exponential
+            .choose(&candidates, &mut rng)
+            .unwrap();
+        counts[stop.id as usize - 1] += 1;
+    }
+
+    assert!(counts[0] > counts[1]);
+    assert!(counts[1] > counts[2]);
+}
+
+#[rstest]
+#[case(CandidateBias::Uniform)]
+#[case(CandidateBias::Rank)]
+#[case(CandidateBias::Exponential)]
+fn empty_candidate_list_has_no_choice(#[case] bias: CandidateBias) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    assert!(bias.choose(&[], &mut rng).is_none());
+}
@@ -0,0 +1,60 @@
+use rstest::rstest;
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    solvers::grasp::elite_pool::ElitePool,
+};
+
+use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
+use crate::tests::fixtures::stops_fixture::stops;
+
+fn solution(route_factory: &RouteFactory, stops: &[Stop], tour: &[usize]) -> Vec<Route> {
+    vec![route_factory(
+        tour.iter().map(|index| stops[*index]).collect(),
+    )]
+}
+
+#[rstest]
+fn elite_pool_keeps_solutions_sorted_by_cost(stops: Vec<Stop>, route_factory: RouteFactory) {
+    let mut pool = ElitePool::new(3);
+
+    assert!(pool.insert(solution(&route_factory, &stops, &[0, 1, 2, 0]), 8.0));
+    assert!(pool.insert(solution(&route_factory, &stops, &[0, 2, 1, 0]), 7.0));
+    assert!(pool.insert(solution(&route_factory, &stops, &[0, 3, 1, 0]), 9.0));
+
+    let costs: Vec<f64> = pool.solutions.iter().map(|(_, cost)| *cost).collect();
+
+    assert_eq!(costs, vec![7.0, 8.0, 9.0]);
+}
+
+#[rstest]
+fn elite_pool_rejects_duplicates(stops: Vec<Stop>, route_factory: RouteFactory) {
+    let mut pool = ElitePool::new(3);
+
+    assert!(pool.insert(solution(&route_factory, &stops, &[0, 1, 2, 0]), 8.0));
+    assert!(!pool.insert(solution(&route_factory, &stops, &[0, 1, 2, 0]), 8.0));
+    assert_eq!(pool.solutions.len(), 1);
+}
+
+#[rstest]
+fn full_elite_pool_replaces_worst_solution(stops: Vec<Stop>, route_factory: RouteFactory) {
+    let mut pool = ElitePool::new(2);
+
+    pool.insert(solution(&route_factory, &stops, &[0, 1, 2, 0]), 8.0);
+    pool.insert(solution(&route_factory, &stops, &[0, 2, 1, 0]), 9.0);
+
+    assert!(!pool.insert(solution(&route_factory, &stops, &[0, 3, 1, 0]), 10.0));
+    assert!(pool.insert(solution(&route_factory, &stops, &[0, 3, 2, 0]), 6.0));
+
+    let costs: Vec<f64> = pool.solutions.iter().map(|(_, cost)| *cost).collect();
+
+    assert_eq!(costs, vec![6.0, 8.0]);
+}
+
+#[rstest]
+fn empty_elite_pool_is_disabled(stops: Vec<Stop>, route_factory: RouteFactory) {
+    let mut pool = ElitePool::default();
+
+    assert!(!pool.insert(solution(&route_factory, &stops, &[0, 1, 2, 0]), 8.0));
+    assert!(pool.solutions.is_empty());
+}
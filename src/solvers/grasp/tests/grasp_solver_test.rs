@@ -1,9 +1,18 @@
+use std::collections::HashMap;
+
 use crate::services::distance::distance_service::DistanceMatrix;
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 use rstest::rstest;
 
-use crate::{domain::stop::Stop, solvers::grasp::grasp_solver::GraspSolver};
+use crate::{
+    domain::stop::Stop,
+    solvers::grasp::{
+        candidate_list::{CandidateBias, CandidateList},
+        grasp_solver::GraspSolver,
+    },
+    solvers::solution::Solution,
+};
 
 use crate::tests::fixtures::distances_fixture::distances;
 use crate::tests::fixtures::stops_fixture::stops;
@@ -26,5 +35,89 @@ fn grasp_solution_is_correct(
 
     assert_ne!(solution_v1.len(), 0);
     assert_ne!(solution_v2.len(), 0);
-    assert_eq!(solver.solution.total_distance, 10.0);
+    assert_eq!(solver.solution.total_distance, 8.0);
+}
+
+#[rstest]
+fn reactive_grasp_with_path_relinking_solution_is_correct(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let vehicles = vehicle_factory(2);
+
+    let mut solver = GraspSolver::new(3, vehicles, &distances, 5, stops, &mut rng)
+        .with_reactive_rcl(vec![1, 2, 3], 2)
+        .with_path_relinking(3);
+    solver.solve();
+
+    assert_eq!(solver.solution.total_distance, 8.0);
+}
+
+#[rstest]
+#[case(CandidateList::Value { alpha: 0.3 }, CandidateBias::Uniform)]
+#[case(CandidateList::Value { alpha: 0.6 }, CandidateBias::Rank)]
+#[case(CandidateList::Cardinality, CandidateBias::Exponential)]
+fn grasp_with_candidate_list_solution_is_correct(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+    #[case] candidate_list: CandidateList,
+    #[case] candidate_bias: CandidateBias,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let vehicles = vehicle_factory(2);
+
+    let mut solver = GraspSolver::new(3, vehicles, &distances, 5, stops, &mut rng)
+        .with_candidate_list(candidate_list)
+        .with_candidate_bias(candidate_bias);
+    solver.solve();
+
+    assert_eq!(solver.solution.total_distance, 8.0);
+}
+
+fn yesterday() -> Solution {
+    Solution {
+        total_distance: 0.0,
+        result: HashMap::from([(0, vec![0, 2, 3, 1, 0]), (1, vec![0, 0])]),
+    }
+}
+
+#[rstest]
+fn grasp_keeps_initial_solution_as_incumbent(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let vehicles = vehicle_factory(2);
+
+    let mut solver = GraspSolver::new(3, vehicles, &distances, 1, stops, &mut rng)
+        .with_initial_solution(&yesterday())
+        .unwrap();
+
+    assert_eq!(solver.solution.total_distance, 8.0);
+
+    solver.solve();
+
+    assert_eq!(solver.solution.total_distance, 8.0);
+}
+
+#[rstest]
+fn grasp_rejects_invalid_initial_solution(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let invalid = Solution {
+        total_distance: 0.0,
+        result: HashMap::from([(5, vec![0, 1, 0])]),
+    };
+
+    let solver = GraspSolver::new(3, vehicle_factory(2), &distances, 1, stops, &mut rng)
+        .with_initial_solution(&invalid);
+
+    assert!(solver.is_err());
 }
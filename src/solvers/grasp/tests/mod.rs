@@ -1,2 +1,17 @@
+#[cfg(test)]
+pub mod candidate_list_test;
+
+#[cfg(test)]
+pub mod elite_pool_test;
+
 #[cfg(test)]
 pub mod grasp_solver_test;
+
+#[cfg(test)]
+pub mod parallel_grasp_solver_test;
+
+#[cfg(test)]
+pub mod path_relinking_test;
+
+#[cfg(test)]
+pub mod reactive_rcl_test;
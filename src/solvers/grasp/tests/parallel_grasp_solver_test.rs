@@ -0,0 +1,45 @@
+use crate::services::distance::distance_service::DistanceMatrix;
+use rstest::rstest;
+
+use crate::{domain::stop::Stop, solvers::grasp::parallel_grasp_solver::ParallelGraspSolver};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+#[rstest]
+fn parallel_grasp_solution_is_correct(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut solver = ParallelGraspSolver::new(3, vehicle_factory(2), &distances, 3, stops, 4, 0);
+    solver.solve();
+
+    let solution_v1 = solver.solution.result.get(&0).unwrap();
+    let solution_v2 = solver.solution.result.get(&1).unwrap();
+
+    assert_ne!(solution_v1.len(), 0);
+    assert_ne!(solution_v2.len(), 0);
+    assert_eq!(solver.solution.total_distance, 8.0);
+}
+
+#[rstest]
+fn parallel_grasp_is_reproducible(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut solver1 =
+        ParallelGraspSolver::new(2, vehicle_factory(2), &distances, 3, stops.clone(), 3, 7);
+    let mut solver2 = ParallelGraspSolver::new(2, vehicle_factory(2), &distances, 3, stops, 3, 7);
+
+    solver1.solve();
+    solver2.solve();
+
+    assert_eq!(
+        solver1.solution.total_distance,
+        solver2.solution.total_distance
+    );
+    assert_eq!(solver1.solution.result, solver2.solution.result);
+}
@@ -0,0 +1,106 @@
+use rstest::rstest;
+
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    services::distance::distance_service::DistanceMatrix,
+    solvers::grasp::path_relinking::PathRelinking,
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
+use crate::tests::fixtures::stops_fixture::stops;
+
+fn routes(route_factory: &RouteFactory, stops: &[Stop], tours: Vec<Vec<usize>>) -> Vec<Route> {
+    tours
+        .into_iter()
+        .enumerate()
+        .map(|(vehicle_id, tour)| {
+            let mut route = route_factory(tour.iter().map(|index| stops[*index]).collect());
+            route.vehicle = Vehicle::new(vehicle_id as u32, 100);
+            route
+        })
+        .collect()
+}
+
+#[rstest]
+fn relink_returns_best_intermediate_solution(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_factory: RouteFactory,
+) {
+    let path_relinking = PathRelinking::new(stops.clone(), &distances);
+    let initial = routes(
+        &route_factory,
+        &stops,
+        vec![vec![0, 1, 0], vec![0, 2, 3, 0]],
+    );
+    let guiding = routes(
+        &route_factory,
+        &stops,
+        vec![vec![0, 2, 3, 1, 0], vec![0, 0]],
+    );
+
+    let relinked = path_relinking.relink(&initial, &guiding).unwrap();
+
+    let stop_ids: Vec<Vec<u32>> = relinked
+        .iter()
+        .map(|route| route.stops.iter().map(|stop| stop.id).collect())
+        .collect();
+    let total_distance: f64 = relinked.iter().map(|route| route.total_distance()).sum();
+
+    assert_eq!(stop_ids, vec![vec![0, 2, 1, 0], vec![0, 3, 0]]);
+    assert_eq!(total_distance, 14.0);
+}
+
+#[rstest]
+fn relink_identical_solutions_has_no_intermediate(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_factory: RouteFactory,
+) {
+    let path_relinking = PathRelinking::new(stops.clone(), &distances);
+    let initial = routes(
+        &route_factory,
+        &stops,
+        vec![vec![0, 1, 0], vec![0, 2, 3, 0]],
+    );
+    let guiding = routes(
+        &route_factory,
+        &stops,
+        vec![vec![0, 1, 0], vec![0, 2, 3, 0]],
+    );
+
+    assert!(path_relinking.relink(&initial, &guiding).is_none());
+}
+
+#[rstest]
+fn relink_ignores_overloaded_intermediates(distances: DistanceMatrix) {
+    let stops = vec![
+        Stop::new(0, 0),
+        Stop::new(1, 6),
+        Stop::new(2, 6),
+        Stop::new(3, 1),
+        Stop::new(4, 0),
+    ];
+    let path_relinking = PathRelinking::new(stops.clone(), &distances);
+    let tour = |vehicle_id: u32, stop_ids: &[usize]| {
+        let mut route = Route::new(Vehicle::new(vehicle_id, 10));
+        let mut previous = stops[0];
+        route.add_stop(previous, 0.0).unwrap();
+
+        for index in stop_ids.iter().chain([0].iter()) {
+            let stop = stops[*index];
+            route
+                .add_stop(stop, distances[&(previous.id, stop.id)])
+                .unwrap();
+            previous = stop;
+        }
+
+        route
+    };
+
+    let initial = vec![tour(0, &[1, 3]), tour(1, &[2])];
+    let guiding = vec![tour(0, &[2, 3]), tour(1, &[1])];
+
+    assert!(path_relinking.relink(&initial, &guiding).is_none());
+}
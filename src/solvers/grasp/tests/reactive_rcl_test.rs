@@ -0,0 +1,36 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::solvers::grasp::reactive_rcl::ReactiveRcl;
+
+#[rstest]
+fn reactive_rcl_starts_uniform() {
+    let reactive_rcl = ReactiveRcl::new(vec![1, 2, 4, 8], 5);
+
+    assert_eq!(reactive_rcl.probabilities, vec![0.25; 4]);
+}
+
+#[rstest]
+fn reactive_rcl_favours_better_sizes() {
+    let mut reactive_rcl = ReactiveRcl::new(vec![1, 3], 2);
+
+    reactive_rcl.record(0, 8.0, 8.0);
+    assert_eq!(reactive_rcl.probabilities, vec![0.5, 0.5]);
+
+    reactive_rcl.record(1, 12.0, 8.0);
+    assert!(reactive_rcl.probabilities[0] > reactive_rcl.probabilities[1]);
+    assert!((reactive_rcl.probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+}
+
+#[rstest]
+fn reactive_rcl_chooses_valid_index() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let reactive_rcl = ReactiveRcl::new(vec![1, 2, 3], 5);
+
+    for _ in 0..10 {
+        assert!(reactive_rcl.choose(&mut rng).unwrap() < 3);
+    }
+
+    assert!(ReactiveRcl::new(vec![], 5).choose(&mut rng).is_none());
+}
@@ -1,6 +1,6 @@
 use crate::{
     domain::{stop::Stop, vehicle::Vehicle},
-    services::{distance::distance_service::DistanceMatrix, route::route_service::RouteService},
+    services::{distance::distance_oracle::IntoDistanceOracle, route::route_service::RouteService},
     solvers::solution::Solution,
 };
 
@@ -12,7 +12,7 @@ pub struct GreedySolver {
 impl GreedySolver {
     pub fn new(
         vehicles: Vec<Vehicle>,
-        distances: &DistanceMatrix,
+        distances: impl IntoDistanceOracle,
         stops: Vec<Stop>,
     ) -> GreedySolver {
         GreedySolver {
@@ -1,4 +1,6 @@
+pub mod branch_and_bound;
 pub mod genetic;
 pub mod grasp;
 pub mod greedy;
 pub mod solution;
+pub mod tabu;
@@ -1,6 +1,13 @@
 use std::collections::HashMap;
 
-use crate::services::route::route_service::RouteMap;
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    lower_bound::LowerBound,
+    services::{
+        distance::distance_service::{DistanceService, StopsMap},
+        route::route_service::RouteMap,
+    },
+};
 
 type Result = HashMap<u32, Vec<u32>>;
 
@@ -41,4 +48,73 @@ impl Solution {
     pub fn is_better_than(&self, other: &Solution) -> bool {
         self.total_distance < other.total_distance
     }
+
+    pub fn gap(&self, lower_bound: &LowerBound) -> f64 {
+        let bound = lower_bound.distance();
+
+        if bound <= 0.0 {
+            return match self.total_distance <= 0.0 {
+                true => 0.0,
+                false => f64::INFINITY,
+            };
+        }
+
+        (self.total_distance - bound) / bound
+    }
+
+    fn build_route(
+        vehicle_id: u32,
+        stop_ids: &[u32],
+        stops: &StopsMap,
+        distance_service: &DistanceService,
+    ) -> Option<Route> {
+        let mut route = Route::new(Vehicle::new(vehicle_id, u32::MAX));
+        let mut previous: Option<&Stop> = None;
+
+        for stop_id in stop_ids {
+            let stop = stops.get(stop_id)?;
+
+            let distance = match previous {
+                Some(previous) => distance_service.get_distance(previous, stop)?,
+                None => 0.0,
+            };
+
+            route.add_stop(*stop, distance).ok()?;
+            previous = Some(stop);
+        }
+
+        Some(route)
+    }
+
+    pub(crate) fn improve_routes<F>(
+        &mut self,
+        stops: &StopsMap,
+        distance_service: &DistanceService,
+        improve: F,
+    ) -> Option<()>
+    where
+        F: Fn(&mut Route) -> Option<()>,
+    {
+        let mut distance_change = 0.0;
+        let mut result = Result::new();
+
+        // Commit only once every route improved, so a failure leaves the solution intact.
+        for (vehicle_id, stop_ids) in self.result.iter() {
+            let mut route = Self::build_route(*vehicle_id, stop_ids, stops, distance_service)?;
+            let initial_distance = route.total_distance();
+
+            improve(&mut route)?;
+
+            distance_change += route.total_distance() - initial_distance;
+            result.insert(
+                *vehicle_id,
+                route.stops.iter().map(|stop| stop.id).collect(),
+            );
+        }
+
+        self.result = result;
+        self.total_distance += distance_change;
+
+        Some(())
+    }
 }
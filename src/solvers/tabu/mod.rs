@@ -0,0 +1,4 @@
+mod tabu_list;
+mod tabu_move;
+pub mod tabu_solver;
+mod tests;
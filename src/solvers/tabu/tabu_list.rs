@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+pub(crate) type TabuAttribute = (u32, u32);
+
+pub(crate) struct TabuList {
+    tenure: u32,
+    expirations: HashMap<TabuAttribute, u32>,
+}
+
+impl TabuList {
+    pub(crate) fn new(tenure: u32) -> Self {
+        Self {
+            tenure,
+            expirations: HashMap::new(),
+        }
+    }
+
+    pub(crate) fn add(&mut self, attribute: TabuAttribute, iteration: u32) {
+        self.expirations.insert(attribute, iteration + self.tenure);
+    }
+
+    pub(crate) fn is_tabu(&self, attribute: &TabuAttribute, iteration: u32) -> bool {
+        match self.expirations.get(attribute) {
+            Some(expiration) => iteration < *expiration,
+            None => false,
+        }
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.expirations.clear();
+    }
+}
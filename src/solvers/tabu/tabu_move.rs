@@ -0,0 +1,295 @@
+use crate::{
+    domain::{route::Route, stop::Stop},
+    services::distance::distance_service::DistanceService,
+    stop_swapper::{path::Path, path_node::PathNode, StopSwapper},
+};
+
+use super::tabu_list::TabuAttribute;
+
+pub(super) type StopAddress = (usize, usize);
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(super) enum MoveKind {
+    Relocate,
+    Swap,
+}
+
+#[derive(Copy, Clone)]
+pub(super) struct TabuMove {
+    pub(super) kind: MoveKind,
+    pub(super) origin: StopAddress,
+    pub(super) destination: StopAddress,
+    pub(super) cost: f64,
+}
+
+impl TabuMove {
+    pub(super) fn new(
+        kind: MoveKind,
+        origin: StopAddress,
+        destination: StopAddress,
+        cost: f64,
+    ) -> Self {
+        Self {
+            kind,
+            origin,
+            destination,
+            cost,
+        }
+    }
+
+    fn removal_cost(
+        stops: &[Stop],
+        stop_index: usize,
+        distance_service: &DistanceService,
+    ) -> Option<f64> {
+        let path = Path::from_stop_index(stops, stop_index, distance_service)?;
+
+        Some(distance_service.get_distance(path.prev.stop, path.next.stop)? - path.cost)
+    }
+
+    fn insertion_cost(
+        stops: &[Stop],
+        stop: &Stop,
+        position: usize,
+        distance_service: &DistanceService,
+    ) -> Option<f64> {
+        let prev = stops.get(position - 1)?;
+        let next = stops.get(position)?;
+
+        Some(
+            distance_service.get_distance(prev, stop)?
+                + distance_service.get_distance(stop, next)?
+                - distance_service.get_distance(prev, next)?,
+        )
+    }
+
+    fn replacement_cost(
+        stops: &[Stop],
+        stop_index: usize,
+        stop: &Stop,
+        distance_service: &DistanceService,
+    ) -> Option<f64> {
+        let path = Path::from_stop_index(stops, stop_index, distance_service)?;
+        let replaced_path = Path::new(
+            path.prev,
+            PathNode::new(stop_index, stop),
+            path.next,
+            distance_service,
+        )?;
+
+        Some(replaced_path.cost - path.cost)
+    }
+
+    fn interior_indexes(route: &Route) -> std::ops::Range<usize> {
+        1..route.stops.len().saturating_sub(1)
+    }
+
+    pub(super) fn relocations(routes: &[Route], distance_service: &DistanceService) -> Vec<Self> {
+        let mut moves = Vec::new();
+
+        for (origin_route, route) in routes.iter().enumerate() {
+            for stop_index in Self::interior_indexes(route) {
+                let stop = &route.stops[stop_index];
+
+                let removal_cost =
+                    match Self::removal_cost(&route.stops, stop_index, distance_service) {
+                        Some(cost) => cost,
+                        None => continue,
+                    };
+
+                for (destination_route, destination) in routes.iter().enumerate() {
+                    let same_route = origin_route == destination_route;
+
+                    if !same_route && !destination.can_add_stop(stop) {
+                        continue;
+                    }
+
+                    for position in 1..destination.stops.len() {
+                        if same_route && (position == stop_index || position == stop_index + 1) {
+                            continue;
+                        }
+
+                        let insertion_cost = match Self::insertion_cost(
+                            &destination.stops,
+                            stop,
+                            position,
+                            distance_service,
+                        ) {
+                            Some(cost) => cost,
+                            None => continue,
+                        };
+
+                        moves.push(Self::new(
+                            MoveKind::Relocate,
+                            (origin_route, stop_index),
+                            (destination_route, position),
+                            removal_cost + insertion_cost,
+                        ));
+                    }
+                }
+            }
+        }
+
+        moves
+    }
+
+    fn swap_cost(
+        routes: &[Route],
+        origin: StopAddress,
+        destination: StopAddress,
+        stop_swapper: &StopSwapper,
+    ) -> Option<f64> {
+        let distance_service = &stop_swapper.distance_service;
+        let origin_stops = &routes[origin.0].stops;
+        let destination_stops = &routes[destination.0].stops;
+
+        if origin.0 == destination.0 {
+            let path1 = Path::from_stop_index(origin_stops, origin.1, distance_service)?;
+            let path2 = Path::from_stop_index(destination_stops, destination.1, distance_service)?;
+
+            return Some(stop_swapper.calculate_swap_cost(&path1, &path2));
+        }
+
+        Some(
+            Self::replacement_cost(
+                origin_stops,
+                origin.1,
+                &destination_stops[destination.1],
+                distance_service,
+            )? + Self::replacement_cost(
+                destination_stops,
+                destination.1,
+                &origin_stops[origin.1],
+                distance_service,
+            )?,
+        )
+    }
+
+    fn can_swap(route: &Route, stop_index: usize, other: &Route, other_index: usize) -> bool {
+        route.can_replace_stop(stop_index, &other.stops[other_index])
+            && other.can_replace_stop(other_index, &route.stops[stop_index])
+    }
+
+    pub(super) fn swaps(routes: &[Route], stop_swapper: &StopSwapper) -> Vec<Self> {
+        let mut moves = Vec::new();
+
+        for (origin_route, route) in routes.iter().enumerate() {
+            for (destination_route, destination) in routes.iter().enumerate().skip(origin_route) {
+                let same_route = origin_route == destination_route;
+
+                for stop_index in Self::interior_indexes(route) {
+                    for other_index in Self::interior_indexes(destination) {
+                        if same_route && other_index <= stop_index {
+                            continue;
+                        }
+
+                        if !same_route
+                            && !Self::can_swap(route, stop_index, destination, other_index)
+                        {
+                            continue;
+                        }
+
+                        let origin = (origin_route, stop_index);
+                        let target = (destination_route, other_index);
+
+                        if let Some(cost) = Self::swap_cost(routes, origin, target, stop_swapper) {
+                            moves.push(Self::new(MoveKind::Swap, origin, target, cost));
+                        }
+                    }
+                }
+            }
+        }
+
+        moves
+    }
+
+    pub(super) fn neighbourhood(routes: &[Route], stop_swapper: &StopSwapper) -> Vec<Self> {
+        let mut moves = Self::relocations(routes, &stop_swapper.distance_service);
+        moves.extend(Self::swaps(routes, stop_swapper));
+
+        moves
+    }
+
+    fn moved_stops(&self, routes: &[Route]) -> Vec<(Stop, u32, u32)> {
+        let origin_route = &routes[self.origin.0];
+        let destination_route = &routes[self.destination.0];
+        let stop = origin_route.stops[self.origin.1];
+
+        match self.kind {
+            MoveKind::Relocate => {
+                vec![(stop, origin_route.vehicle.id, destination_route.vehicle.id)]
+            }
+            MoveKind::Swap => vec![
+                (stop, origin_route.vehicle.id, destination_route.vehicle.id),
+                (
+                    destination_route.stops[self.destination.1],
+                    destination_route.vehicle.id,
+                    origin_route.vehicle.id,
+                ),
+            ],
+        }
+    }
+
+    pub(super) fn introduced_attributes(&self, routes: &[Route]) -> Vec<TabuAttribute> {
+        self.moved_stops(routes)
+            .iter()
+            .map(|(stop, _, to)| (stop.id, *to))
+            .collect()
+    }
+
+    pub(super) fn dropped_attributes(&self, routes: &[Route]) -> Vec<TabuAttribute> {
+        self.moved_stops(routes)
+            .iter()
+            .map(|(stop, from, _)| (stop.id, *from))
+            .collect()
+    }
+
+    pub(super) fn apply(
+        &self,
+        routes: &mut [Route],
+        distance_service: &DistanceService,
+    ) -> Option<()> {
+        let (origin_route, stop_index) = self.origin;
+        let (destination_route, mut destination_index) = self.destination;
+
+        match self.kind {
+            MoveKind::Relocate => {
+                let removal_cost =
+                    Self::removal_cost(&routes[origin_route].stops, stop_index, distance_service)?;
+
+                let stop = routes[origin_route].remove_stop(stop_index, -removal_cost);
+
+                if origin_route == destination_route && destination_index > stop_index {
+                    destination_index -= 1;
+                }
+
+                routes[destination_route]
+                    .insert_stop(stop, destination_index, self.cost - removal_cost)
+                    .ok()?;
+            }
+            MoveKind::Swap if origin_route == destination_route => {
+                routes[origin_route].swap_stops(stop_index, destination_index, self.cost);
+            }
+            MoveKind::Swap => {
+                let stop = routes[origin_route].stops[stop_index];
+                let other = routes[destination_route].stops[destination_index];
+
+                let origin_cost = Self::replacement_cost(
+                    &routes[origin_route].stops,
+                    stop_index,
+                    &other,
+                    distance_service,
+                )?;
+
+                routes[origin_route]
+                    .replace_stop(stop_index, other, origin_cost)
+                    .ok()?;
+                routes[destination_route]
+                    .replace_stop(destination_index, stop, self.cost - origin_cost)
+                    .ok()?;
+            }
+        }
+
+        Some(())
+    }
+}
@@ -0,0 +1,225 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    errors::solver::invalid_solution::InvalidSolutionError,
+    services::{
+        distance::distance_oracle::IntoDistanceOracle,
+        route::route_service::{RouteMap, RouteService},
+    },
+    solvers::solution::Solution,
+    stop_swapper::StopSwapper,
+};
+
+use super::{tabu_list::TabuList, tabu_move::TabuMove};
+
+pub struct TabuSolver<'a, R: Rng + ?Sized> {
+    rng: &'a mut R,
+    pub solution: Solution,
+    pub(super) routes: Vec<Route>,
+    pub(super) tabu_list: TabuList,
+    pub(super) stop_swapper: StopSwapper,
+    route_service: RouteService,
+    max_iterations: u32,
+    current_iteration: u32,
+    max_iterations_without_improvement: u32,
+    pub(super) iterations_without_improvement: u32,
+    diversification_moves: usize,
+}
+
+impl<'a, R: Rng + ?Sized> TabuSolver<'a, R> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        vehicles: Vec<Vehicle>,
+        distances: impl IntoDistanceOracle,
+        stops: Vec<Stop>,
+        tabu_tenure: u32,
+        max_iterations: u32,
+        max_iterations_without_improvement: u32,
+        diversification_moves: usize,
+        rng: &'a mut R,
+    ) -> Self {
+        let oracle = distances.into_oracle();
+
+        Self {
+            rng,
+            max_iterations,
+            diversification_moves,
+            max_iterations_without_improvement,
+            routes: Vec::new(),
+            solution: Solution::default(),
+            tabu_list: TabuList::new(tabu_tenure),
+            current_iteration: Default::default(),
+            iterations_without_improvement: Default::default(),
+            stop_swapper: StopSwapper::new(stops.clone(), &oracle),
+            route_service: RouteService::new(vehicles, &oracle, stops),
+        }
+    }
+
+    pub fn with_initial_solution(
+        mut self,
+        solution: &Solution,
+    ) -> Result<Self, InvalidSolutionError> {
+        self.route_service.load_solution(solution)?;
+
+        self.routes = self
+            .route_service
+            .get_all_routes()
+            .values()
+            .cloned()
+            .collect();
+
+        Ok(self)
+    }
+
+    pub fn solve(&mut self) {
+        if self.routes.is_empty() {
+            self.generate_initial_solution();
+        }
+
+        self.update_solution();
+
+        while !self.stop_condition_met() {
+            self.run_iteration();
+
+            if self.is_stagnated() {
+                self.diversify();
+            }
+
+            self.current_iteration += 1;
+        }
+    }
+
+    fn stop_condition_met(&self) -> bool {
+        self.current_iteration >= self.max_iterations
+    }
+
+    fn is_stagnated(&self) -> bool {
+        self.iterations_without_improvement >= self.max_iterations_without_improvement
+    }
+
+    fn generate_initial_solution(&mut self) {
+        let vehicle_ids: Vec<u32> = self
+            .route_service
+            .get_all_routes()
+            .keys()
+            .cloned()
+            .collect();
+
+        self.route_service.reset();
+        self.route_service.assign_starting_points();
+
+        while self.route_service.has_available_stop().unwrap() {
+            for vehicle_id in vehicle_ids.iter() {
+                let stop_id = match self.route_service.get_nearest_stop(*vehicle_id) {
+                    None => break,
+                    Some(stop) => stop.id,
+                };
+
+                self.route_service
+                    .assign_stop_to_route(*vehicle_id, stop_id)
+                    .unwrap();
+            }
+        }
+
+        self.route_service.assign_stop_points();
+
+        self.routes = self
+            .route_service
+            .get_all_routes()
+            .values()
+            .cloned()
+            .collect();
+    }
+
+    fn total_distance(&self) -> f64 {
+        self.routes.iter().map(|route| route.total_distance()).sum()
+    }
+
+    fn update_solution(&mut self) -> bool {
+        let total_distance = self.total_distance();
+
+        if total_distance >= self.solution.total_distance {
+            return false;
+        }
+
+        let route_map: RouteMap = self
+            .routes
+            .iter()
+            .cloned()
+            .map(|route| (route.vehicle.id, route))
+            .collect();
+
+        self.solution = Solution::new(&route_map, total_distance);
+
+        true
+    }
+
+    fn satisfies_aspiration(&self, tabu_move: &TabuMove) -> bool {
+        self.total_distance() + tabu_move.cost < self.solution.total_distance
+    }
+
+    pub(super) fn is_admissible(&self, tabu_move: &TabuMove) -> bool {
+        let is_tabu = tabu_move
+            .introduced_attributes(&self.routes)
+            .iter()
+            .any(|attribute| self.tabu_list.is_tabu(attribute, self.current_iteration));
+
+        !is_tabu || self.satisfies_aspiration(tabu_move)
+    }
+
+    pub(super) fn find_best_move(&self) -> Option<TabuMove> {
+        TabuMove::neighbourhood(&self.routes, &self.stop_swapper)
+            .into_iter()
+            .filter(|tabu_move| self.is_admissible(tabu_move))
+            .min_by(|move1, move2| move1.cost.partial_cmp(&move2.cost).unwrap())
+    }
+
+    fn apply_move(&mut self, tabu_move: &TabuMove) -> Option<()> {
+        let dropped_attributes = tabu_move.dropped_attributes(&self.routes);
+
+        tabu_move.apply(&mut self.routes, &self.stop_swapper.distance_service)?;
+
+        for attribute in dropped_attributes {
+            self.tabu_list.add(attribute, self.current_iteration);
+        }
+
+        Some(())
+    }
+
+    fn run_iteration(&mut self) {
+        let best_move = match self.find_best_move() {
+            Some(best_move) => best_move,
+            None => {
+                self.iterations_without_improvement += 1;
+                return;
+            }
+        };
+
+        self.apply_move(&best_move);
+
+        if self.update_solution() {
+            self.iterations_without_improvement = 0;
+        } else {
+            self.iterations_without_improvement += 1;
+        }
+    }
+
+    fn diversify(&mut self) {
+        for _ in 0..self.diversification_moves {
+            let relocations =
+                TabuMove::relocations(&self.routes, &self.stop_swapper.distance_service);
+
+            let random_move = match relocations.choose(self.rng) {
+                Some(random_move) => *random_move,
+                None => break,
+            };
+
+            random_move.apply(&mut self.routes, &self.stop_swapper.distance_service);
+        }
+
+        self.tabu_list.clear();
+        self.update_solution();
+        self.iterations_without_improvement = 0;
+    }
+}
@@ -0,0 +1,5 @@
+#[cfg(test)]
+pub mod tabu_list_test;
+
+#[cfg(test)]
+pub mod tabu_solver_test;
@@ -0,0 +1,31 @@
+use crate::solvers::tabu::tabu_list::TabuList;
+
+#[test]
+fn attribute_is_tabu_during_tenure() {
+    let mut tabu_list = TabuList::new(3);
+
+    tabu_list.add((1, 0), 2);
+
+    assert!(tabu_list.is_tabu(&(1, 0), 2));
+    assert!(tabu_list.is_tabu(&(1, 0), 4));
+    assert!(!tabu_list.is_tabu(&(1, 1), 4));
+}
+
+#[test]
+fn attribute_expires_after_tenure() {
+    let mut tabu_list = TabuList::new(3);
+
+    tabu_list.add((1, 0), 2);
+
+    assert!(!tabu_list.is_tabu(&(1, 0), 5));
+}
+
+#[test]
+fn can_clear_tabu_list() {
+    let mut tabu_list = TabuList::new(3);
+
+    tabu_list.add((1, 0), 0);
+    tabu_list.clear();
+
+    assert!(!tabu_list.is_tabu(&(1, 0), 1));
+}
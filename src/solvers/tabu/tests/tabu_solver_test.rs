@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::services::distance::distance_service::DistanceMatrix;
+use crate::{
+    domain::stop::Stop,
+    solvers::{
+        solution::Solution,
+        tabu::{tabu_move::TabuMove, tabu_solver::TabuSolver},
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+#[rstest]
+fn tabu_solution_is_correct(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let vehicles = vehicle_factory(2);
+
+    let mut solver = TabuSolver::new(vehicles, &distances, stops, 3, 20, 5, 2, &mut rng);
+    solver.solve();
+
+    let solution_v1 = solver.solution.result.get(&0).unwrap();
+    let solution_v2 = solver.solution.result.get(&1).unwrap();
+
+    assert_ne!(solution_v1.len(), 0);
+    assert_ne!(solution_v2.len(), 0);
+    assert_eq!(solver.solution.total_distance, 8.0);
+}
+
+#[rstest]
+fn tabu_solution_distance_is_consistent(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let vehicles = vehicle_factory(2);
+
+    let mut solver = TabuSolver::new(vehicles, &distances, stops, 2, 30, 3, 3, &mut rng);
+    solver.solve();
+
+    let total_distance: f64 = solver
+        .solution
+        .result
+        .values()
+        .flat_map(|route| route.windows(2))
+        .map(|window| match window[0] == window[1] {
+            true => 0.0,
+            false => *distances.get(&(window[0], window[1])).unwrap(),
+        })
+        .sum();
+
+    assert_eq!(solver.solution.total_distance, total_distance);
+}
+
+fn yesterday() -> Solution {
+    Solution {
+        total_distance: 0.0,
+        result: HashMap::from([(0, vec![0, 2, 3, 1, 0]), (1, vec![0, 0])]),
+    }
+}
+
+#[rstest]
+fn tabu_starts_from_initial_solution(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let vehicles = vehicle_factory(2);
+
+    let mut solver = TabuSolver::new(vehicles, &distances, stops, 3, 0, 5, 2, &mut rng)
+        .with_initial_solution(&yesterday())
+        .unwrap();
+    solver.solve();
+
+    assert_eq!(solver.solution.total_distance, 8.0);
+    assert_eq!(solver.solution.result, yesterday().result);
+}
+
+fn detour() -> Solution {
+    Solution {
+        total_distance: 0.0,
+        result: HashMap::from([(0, vec![0, 3, 2, 1, 0]), (1, vec![0, 0])]),
+    }
+}
+
+#[rstest]
+fn tabu_move_is_admissible_when_it_beats_the_best_solution(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let vehicles = vehicle_factory(2);
+
+    let mut solver = TabuSolver::new(vehicles, &distances, stops, 3, 0, 5, 2, &mut rng)
+        .with_initial_solution(&detour())
+        .unwrap();
+    solver.solve();
+
+    let neighbourhood = TabuMove::neighbourhood(&solver.routes, &solver.stop_swapper);
+    let (improving, worsening): (Vec<TabuMove>, Vec<TabuMove>) = neighbourhood
+        .into_iter()
+        .partition(|tabu_move| tabu_move.cost < 0.0);
+    let best_move = improving
+        .into_iter()
+        .min_by(|move1, move2| move1.cost.total_cmp(&move2.cost))
+        .unwrap();
+
+    for tabu_move in worsening.iter().chain([best_move].iter()) {
+        for attribute in tabu_move.introduced_attributes(&solver.routes) {
+            solver.tabu_list.add(attribute, 0);
+        }
+    }
+
+    assert_eq!(solver.solution.total_distance, 12.0);
+    assert!(solver.is_admissible(&best_move));
+    assert!(!worsening.is_empty());
+    assert!(worsening
+        .iter()
+        .all(|tabu_move| !solver.is_admissible(tabu_move)));
+    assert_eq!(solver.find_best_move().unwrap().cost, best_move.cost);
+}
+
+#[rstest]
+#[case(1, 1)]
+#[case(2, 0)]
+fn tabu_diversifies_after_stagnation_limit(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+    #[case] max_iterations: u32,
+    #[case] iterations_without_improvement: u32,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let vehicles = vehicle_factory(2);
+
+    let mut solver = TabuSolver::new(
+        vehicles,
+        &distances,
+        stops,
+        3,
+        max_iterations,
+        2,
+        2,
+        &mut rng,
+    )
+    .with_initial_solution(&yesterday())
+    .unwrap();
+    solver.solve();
+
+    assert_eq!(
+        solver.iterations_without_improvement,
+        iterations_without_improvement
+    );
+    assert_eq!(solver.solution.total_distance, 8.0);
+}
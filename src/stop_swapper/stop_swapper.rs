@@ -1,6 +1,6 @@
 use crate::{
     domain::stop::Stop,
-    services::distance::distance_service::{DistanceMatrix, DistanceService},
+    services::distance::{distance_oracle::IntoDistanceOracle, distance_service::DistanceService},
 };
 
 use super::path::Path;
@@ -10,7 +10,7 @@ pub(crate) struct StopSwapper {
 }
 
 impl StopSwapper {
-    pub fn new(stops: Vec<Stop>, distances: &DistanceMatrix) -> Self {
+    pub fn new(stops: Vec<Stop>, distances: impl IntoDistanceOracle) -> Self {
         Self {
             distance_service: DistanceService::new(stops, distances),
         }
@@ -74,10 +74,11 @@ impl StopSwapper {
         (swapped_path1.cost + swapped_path2.cost) - (path1.cost + path2.cost)
     }
 
+    #[cfg(test)]
     pub(crate) fn get_minimum_swap_cost(
         &self,
         path: &Path,
-        stops: &Vec<Stop>,
+        stops: &[Stop],
     ) -> Option<(usize, f64)> {
         stops[..stops.len() - 1]
             .iter()
@@ -0,0 +1,13 @@
+use rstest::fixture;
+
+use crate::{
+    domain::stop::Stop, local_search::held_karp::HeldKarpOptimizer,
+    services::distance::distance_service::DistanceMatrix,
+};
+
+use super::{distances_fixture::distances, stops_fixture::stops};
+
+#[fixture]
+pub fn held_karp(distances: DistanceMatrix, stops: Vec<Stop>) -> HeldKarpOptimizer {
+    HeldKarpOptimizer::new(stops, &distances, 10)
+}
@@ -0,0 +1,13 @@
+use rstest::fixture;
+
+use crate::{
+    domain::stop::Stop, local_search::inter_route::inter_route_searcher::InterRouteSearcher,
+    services::distance::distance_service::DistanceMatrix,
+};
+
+use super::{distances_fixture::distances, stops_fixture::stops};
+
+#[fixture]
+pub fn inter_route_searcher(distances: DistanceMatrix, stops: Vec<Stop>) -> InterRouteSearcher {
+    InterRouteSearcher::new(stops, &distances, 2, None)
+}
@@ -0,0 +1,13 @@
+use rstest::fixture;
+
+use crate::{
+    domain::stop::Stop, local_search::lin_kernighan::LinKernighanSearcher,
+    services::distance::distance_service::DistanceMatrix,
+};
+
+use super::{distances_fixture::distances, stops_fixture::stops};
+
+#[fixture]
+pub fn lin_kernighan(distances: DistanceMatrix, stops: Vec<Stop>) -> LinKernighanSearcher {
+    LinKernighanSearcher::new(stops, &distances, 3, 5)
+}
@@ -1,9 +1,15 @@
 pub mod distances_fixture;
 pub mod genetic_algorithm_fixture;
 pub mod greedy_solver_fixture;
+pub mod held_karp;
+pub mod inter_route;
+pub mod lin_kernighan;
+pub mod or_opt;
 pub mod routes_fixture;
 pub mod services_fixture;
+pub mod simulation_fixture;
 pub mod stop_swapper_fixture;
 pub mod stops_fixture;
+pub mod three_opt;
 pub mod two_opt;
 pub mod vehicles_fixture;
@@ -0,0 +1,19 @@
+use rstest::fixture;
+
+use crate::{
+    domain::stop::Stop,
+    local_search::{improvement_strategy::ImprovementStrategy, or_opt::OrOptSearcher},
+    services::distance::distance_service::DistanceMatrix,
+};
+
+use super::{distances_fixture::distances, stops_fixture::stops};
+
+#[fixture]
+pub fn or_opt(distances: DistanceMatrix, stops: Vec<Stop>) -> OrOptSearcher {
+    OrOptSearcher::new(
+        stops,
+        &distances,
+        ImprovementStrategy::BestImprovement,
+        None,
+    )
+}
@@ -0,0 +1,39 @@
+use rstest::fixture;
+
+pub type ScenarioFactory = Box<dyn Fn(&str) -> String>;
+
+#[fixture]
+pub fn scenario_factory() -> ScenarioFactory {
+    let network = "\
+vehicle 0 10
+stop 0 0
+stop 1 1
+stop 2 1
+stop 3 1
+stop 4 100
+distance 0 1 2.0
+distance 0 2 1.0
+distance 0 3 3.0
+distance 0 4 0.5
+distance 1 0 2.0
+distance 1 2 5.0
+distance 1 3 3.0
+distance 1 4 5.0
+distance 2 0 1.0
+distance 2 1 5.0
+distance 2 3 2.0
+distance 2 4 2.0
+distance 3 0 3.0
+distance 3 1 3.0
+distance 3 2 2.0
+distance 3 4 5.0
+distance 4 0 0.5
+distance 4 1 5.0
+distance 4 2 2.0
+distance 4 3 5.0
+";
+
+    let wrapper = move |events: &str| -> String { format!("{network}{events}") };
+
+    Box::new(wrapper)
+}
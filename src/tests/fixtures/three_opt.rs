@@ -0,0 +1,19 @@
+use rstest::fixture;
+
+use crate::{
+    domain::stop::Stop,
+    local_search::{improvement_strategy::ImprovementStrategy, three_opt::ThreeOptSearcher},
+    services::distance::distance_service::DistanceMatrix,
+};
+
+use super::{distances_fixture::distances, stops_fixture::stops};
+
+#[fixture]
+pub fn three_opt(distances: DistanceMatrix, stops: Vec<Stop>) -> ThreeOptSearcher {
+    ThreeOptSearcher::new(
+        stops,
+        &distances,
+        ImprovementStrategy::FirstImprovement,
+        None,
+    )
+}
@@ -1,7 +1,8 @@
 use rstest::fixture;
 
 use crate::{
-    domain::stop::Stop, local_search::two_opt::TwoOptSearcher,
+    domain::stop::Stop,
+    local_search::{improvement_strategy::ImprovementStrategy, two_opt::TwoOptSearcher},
     services::distance::distance_service::DistanceMatrix,
 };
 
@@ -9,5 +10,10 @@ use super::{distances_fixture::distances, stops_fixture::stops};
 
 #[fixture]
 pub fn two_opt(distances: DistanceMatrix, stops: Vec<Stop>) -> TwoOptSearcher {
-    TwoOptSearcher::new(stops, &distances)
+    TwoOptSearcher::new(
+        stops,
+        &distances,
+        ImprovementStrategy::BestImprovement,
+        None,
+    )
 }